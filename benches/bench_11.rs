@@ -0,0 +1,152 @@
+use std::convert::TryFrom;
+
+use advent2020::day11::bitboard::{self, BitMap};
+use advent2020::day11::{perform_step_a, perform_step_b, Seating, TileMap, RULE_B};
+#[cfg(feature = "parallel")]
+use advent2020::day11::{perform_step_a_parallel, perform_step_b_parallel};
+use advent2020::vector::Vec2;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+#[allow(dead_code)]
+mod generate;
+
+/// The seat map as it was stored before `TileMap` moved to one contiguous buffer: One `Vec` per
+/// row, stepped column by column and collecting the neighbours of every tile into a `Vec`. It is
+/// only kept so the flat map can be compared against it.
+mod nested {
+    use advent2020::day11::{Tile, DIRECTIONS};
+    use advent2020::vector::Vec2;
+
+    pub type NestedMap = Vec<Vec<Tile>>;
+
+    fn get(map: &NestedMap, pos: Vec2<usize>) -> Option<Tile> {
+        map.get(pos.y)?.get(pos.x).copied()
+    }
+
+    fn num_adjacent_occupied(map: &NestedMap, pos: Vec2<usize>) -> usize {
+        let adjacent: Vec<Vec2<usize>> = DIRECTIONS
+            .iter()
+            .filter_map(|&dir| pos.checked_offset(dir))
+            .collect();
+
+        adjacent
+            .into_iter()
+            .filter(|&pos| get(map, pos).is_some_and(|tile| tile.occupied()))
+            .count()
+    }
+
+    fn find_view(map: &NestedMap, pos: Vec2<usize>, dir: Vec2<isize>) -> Option<Tile> {
+        let pos = pos.checked_offset(dir)?;
+        match get(map, pos) {
+            Some(Tile::Floor) => find_view(map, pos, dir),
+            other => other,
+        }
+    }
+
+    fn num_los_occupied(map: &NestedMap, pos: Vec2<usize>) -> usize {
+        DIRECTIONS
+            .iter()
+            .filter(|&&dir| find_view(map, pos, dir) == Some(Tile::SeatTaken))
+            .count()
+    }
+
+    fn perform_step<F>(source: &NestedMap, target: &mut NestedMap, next_tile: F)
+    where
+        F: Fn(Tile, Vec2<usize>) -> Tile,
+    {
+        let height = source.len();
+        let width = source.first().map_or(0, Vec::len);
+        for x in 0..width {
+            for y in 0..height {
+                target[y][x] = next_tile(source[y][x], Vec2::new(x, y));
+            }
+        }
+    }
+
+    pub fn perform_step_a(source: &NestedMap, target: &mut NestedMap) {
+        perform_step(source, target, |tile, pos| {
+            match (tile, num_adjacent_occupied(source, pos)) {
+                (Tile::SeatEmpty, 0) => Tile::SeatTaken,
+                (Tile::SeatTaken, 4..=8) => Tile::SeatEmpty,
+                (tile, _) => tile,
+            }
+        });
+    }
+
+    pub fn perform_step_b(source: &NestedMap, target: &mut NestedMap) {
+        perform_step(source, target, |tile, pos| {
+            match (tile, num_los_occupied(source, pos)) {
+                (Tile::SeatEmpty, 0) => Tile::SeatTaken,
+                (Tile::SeatTaken, 5..=8) => Tile::SeatEmpty,
+                (tile, _) => tile,
+            }
+        });
+    }
+}
+
+fn to_nested(map: &TileMap) -> nested::NestedMap {
+    let (width, height) = map.size();
+    (0..height)
+        .map(|y| {
+            (0..width)
+                .map(|x| map.get(Vec2::new(x, y)).unwrap())
+                .collect()
+        })
+        .collect()
+}
+
+fn bench_steps(c: &mut Criterion) {
+    let size = generate::bench_size();
+    let source: TileMap = generate::day11(size).parse().unwrap();
+    let mut target = TileMap::with_size(source.size());
+    let (width, height) = source.size();
+    let name = |what: &str| format!("day11 {} {}x{}", what, width, height);
+
+    c.bench_function(&name("step a"), |b| {
+        b.iter(|| perform_step_a(black_box(&source), &mut target))
+    });
+    c.bench_function(&name("step b"), |b| {
+        b.iter(|| perform_step_b(black_box(&source), &mut target))
+    });
+
+    // Make sure the reference computes the same generations before comparing against it.
+    let nested_source = to_nested(&source);
+    let mut nested_target = to_nested(&target);
+    perform_step_a(&source, &mut target);
+    nested::perform_step_a(&nested_source, &mut nested_target);
+    assert_eq!(nested_target, to_nested(&target));
+    perform_step_b(&source, &mut target);
+    nested::perform_step_b(&nested_source, &mut nested_target);
+    assert_eq!(nested_target, to_nested(&target));
+
+    c.bench_function(&name("step a nested rows"), |b| {
+        b.iter(|| nested::perform_step_a(black_box(&nested_source), &mut nested_target))
+    });
+    c.bench_function(&name("step b nested rows"), |b| {
+        b.iter(|| nested::perform_step_b(black_box(&nested_source), &mut nested_target))
+    });
+
+    #[cfg(feature = "parallel")]
+    {
+        c.bench_function(&name("step a parallel"), |b| {
+            b.iter(|| perform_step_a_parallel(black_box(&source), &mut target))
+        });
+        c.bench_function(&name("step b parallel"), |b| {
+            b.iter(|| perform_step_b_parallel(black_box(&source), &mut target))
+        });
+    }
+
+    let bits = BitMap::try_from(&source).unwrap();
+    let mut bits_target = BitMap::with_size(bits.size());
+    c.bench_function(&name("step a bitboard"), |b| {
+        b.iter(|| bitboard::perform_step_a(black_box(&bits), &mut bits_target))
+    });
+
+    let seating = Seating::new(&source, RULE_B);
+    c.bench_function(&name("step b visible seats"), |b| {
+        b.iter(|| seating.perform_step(black_box(&source), &mut target))
+    });
+}
+
+criterion_group!(benches, bench_steps);
+criterion_main!(benches);
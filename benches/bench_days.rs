@@ -0,0 +1,89 @@
+use std::str::FromStr;
+
+use advent2020::day01::Day01;
+use advent2020::day02::Day02;
+use advent2020::day03::Day03;
+use advent2020::day04::Day04;
+use advent2020::day05::Day05;
+use advent2020::day06::Day06;
+use advent2020::day07::{BagRule, Day07};
+use advent2020::day08::{fix_loop, run_code, Day08};
+use advent2020::day09::{find_first_broken, num_two_sums, Day09, CHECK_LEN};
+use advent2020::day10::Day10;
+use advent2020::day11::Day11;
+use advent2020::day12::Day12;
+use advent2020::Solution;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+mod generate;
+
+/// Benchmark parsing the input and solving both parts of the day's puzzle.
+fn bench_solution<S: Solution>(c: &mut Criterion, day: &str, input: &str) {
+    let parsed = S::parse(input).unwrap_or_else(|err| panic!("invalid {} input: {}", day, err));
+
+    let mut group = c.benchmark_group(day);
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(input))));
+    group.bench_function("part a", |b| b.iter(|| S::part_a(black_box(&parsed))));
+    group.bench_function("part b", |b| b.iter(|| S::part_b(black_box(&parsed))));
+    group.finish();
+}
+
+fn bench_days(c: &mut Criterion) {
+    let size = generate::bench_size();
+
+    bench_solution::<Day01>(c, "day01", &generate::day01(size));
+    bench_solution::<Day02>(c, "day02", &generate::day02(size));
+    bench_solution::<Day03>(c, "day03", &generate::day03(size));
+    bench_solution::<Day04>(c, "day04", &generate::day04(size));
+    bench_solution::<Day05>(c, "day05", &generate::day05(size));
+    bench_solution::<Day06>(c, "day06", &generate::day06(size));
+    bench_solution::<Day07>(c, "day07", &generate::day07(size));
+    bench_solution::<Day08>(c, "day08", &generate::day08(size));
+    bench_solution::<Day09>(c, "day09", &generate::day09(size));
+    bench_solution::<Day10>(c, "day10", &generate::day10(size));
+    bench_solution::<Day12>(c, "day12", &generate::day12(size));
+
+    // Every part of day 11 runs the seats until they are stable, so fewer samples are taken.
+    let mut group = c.benchmark_group("day11");
+    group.sample_size(10);
+    let input = generate::day11(size);
+    let map = Day11::parse(&input).unwrap();
+    group.bench_function("parse", |b| b.iter(|| Day11::parse(black_box(&input))));
+    group.bench_function("part a", |b| b.iter(|| Day11::part_a(black_box(&map))));
+    group.bench_function("part b", |b| b.iter(|| Day11::part_b(black_box(&map))));
+    group.finish();
+}
+
+fn bench_helpers(c: &mut Criterion) {
+    let size = generate::bench_size();
+
+    let rules = generate::day07(size);
+    c.bench_function("day07 parse bag rules", |b| {
+        b.iter(|| {
+            black_box(&rules)
+                .lines()
+                .map(BagRule::from_str)
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap()
+        })
+    });
+
+    let mut operations = Day08::parse(&generate::day08(size)).unwrap();
+    c.bench_function("day08 run code", |b| {
+        b.iter(|| run_code(black_box(&operations)))
+    });
+    c.bench_function("day08 fix loop", |b| {
+        b.iter(|| fix_loop(black_box(&mut operations)))
+    });
+
+    let numbers = Day09::parse(&generate::day09(size)).unwrap();
+    c.bench_function("day09 two sums", |b| {
+        b.iter(|| num_two_sums(black_box(&numbers[..CHECK_LEN]), numbers[CHECK_LEN]))
+    });
+    c.bench_function("day09 find first broken", |b| {
+        b.iter(|| find_first_broken(black_box(&numbers)))
+    });
+}
+
+criterion_group!(benches, bench_days, bench_helpers);
+criterion_main!(benches);
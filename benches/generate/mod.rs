@@ -0,0 +1,351 @@
+//! Puzzle inputs generated for the benchmarks, so they do not depend on anyone's input files.
+//!
+//! The inputs are random, but every run generates the same ones. Their size is the number of
+//! records in the input, usually lines, and is read from `BENCH_SIZE_VAR`.
+
+use std::env;
+use std::fmt::Write;
+
+use advent2020::day09::CHECK_LEN;
+use advent2020::day11::{Seating, Settlement, TileMap, RULE_A, RULE_B};
+
+/// Environment variable that can be set to the size of the generated inputs.
+pub const BENCH_SIZE_VAR: &str = "AOC_BENCH_SIZE";
+
+/// The size of the generated inputs if `BENCH_SIZE_VAR` is not set, about as large as the real
+/// puzzle inputs.
+pub const DEFAULT_BENCH_SIZE: usize = 200;
+
+/// The size of the generated inputs, as configured by `BENCH_SIZE_VAR`.
+pub fn bench_size() -> usize {
+    match env::var(BENCH_SIZE_VAR) {
+        Ok(size) => size
+            .parse()
+            .unwrap_or_else(|_| panic!("{} must be a number, not {}", BENCH_SIZE_VAR, size)),
+        Err(_) => DEFAULT_BENCH_SIZE,
+    }
+}
+
+/// A linear congruential generator, so every run uses the same inputs.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next(&mut self) -> u64 {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        self.0 >> 16
+    }
+
+    /// A number in `0..bound`.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        self.next() % bound
+    }
+
+    /// Pick one of the items.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+}
+
+/// Expenses between 1 and 2020.
+pub fn day01(size: usize) -> String {
+    let mut rng = Rng::new(1);
+    (0..size)
+        .map(|_| format!("{}\n", 1 + rng.below(2020)))
+        .collect()
+}
+
+/// Password policies with passwords of up to 20 letters.
+pub fn day02(size: usize) -> String {
+    let mut rng = Rng::new(2);
+    let mut input = String::new();
+    for _ in 0..size {
+        let min = 1 + rng.below(5);
+        let max = min + rng.below(10);
+        let c = (b'a' + rng.below(5) as u8) as char;
+        let password: String = (0..1 + rng.below(20))
+            .map(|_| (b'a' + rng.below(5) as u8) as char)
+            .collect();
+        writeln!(input, "{}-{} {}: {}", min, max, c, password).unwrap();
+    }
+
+    input
+}
+
+/// A terrain 31 tiles wide with a tree on every fourth tile.
+pub fn day03(size: usize) -> String {
+    let mut rng = Rng::new(3);
+    let mut input = String::new();
+    for _ in 0..size {
+        for _ in 0..31 {
+            input.push(if rng.below(4) == 0 { '#' } else { '.' });
+        }
+        input.push('\n');
+    }
+
+    input
+}
+
+/// Passports with all fields, some of which are missing a field or have invalid values.
+pub fn day04(size: usize) -> String {
+    let mut rng = Rng::new(4);
+    let mut passports = Vec::with_capacity(size);
+    for _ in 0..size {
+        let mut fields = vec![
+            format!("byr:{}", 1900 + rng.below(120)),
+            format!("iyr:{}", 2005 + rng.below(20)),
+            format!("eyr:{}", 2015 + rng.below(20)),
+            match rng.below(3) {
+                0 => format!("hgt:{}in", 55 + rng.below(25)),
+                _ => format!("hgt:{}cm", 145 + rng.below(50)),
+            },
+            format!("hcl:#{:06x}", rng.below(0x100_0000)),
+            rng.choose(&["ecl:amb", "ecl:blu", "ecl:brn", "ecl:gry", "ecl:xxx"])
+                .to_string(),
+            format!("pid:{:09}", rng.below(1_000_000_000)),
+            format!("cid:{}", rng.below(1000)),
+        ];
+
+        if rng.below(5) == 0 {
+            fields.remove(rng.below(fields.len() as u64) as usize);
+        }
+
+        passports.push(fields.join(" "));
+    }
+
+    passports.join("\n\n") + "\n"
+}
+
+/// Boarding passes of random seats.
+pub fn day05(size: usize) -> String {
+    let mut rng = Rng::new(5);
+    let mut input = String::new();
+    for _ in 0..size {
+        for _ in 0..7 {
+            input.push(*rng.choose(&['F', 'B']));
+        }
+        for _ in 0..3 {
+            input.push(*rng.choose(&['L', 'R']));
+        }
+        input.push('\n');
+    }
+
+    input
+}
+
+/// Groups of up to five people, each answering some of the questions.
+pub fn day06(size: usize) -> String {
+    let mut rng = Rng::new(6);
+    let mut groups = Vec::with_capacity(size);
+    for _ in 0..size {
+        let people: Vec<String> = (0..1 + rng.below(5))
+            .map(|_| {
+                (b'a'..=b'z')
+                    .filter(|_| rng.below(3) == 0)
+                    .map(char::from)
+                    .collect::<String>()
+            })
+            .map(|answers| {
+                if answers.is_empty() {
+                    "a".to_owned()
+                } else {
+                    answers
+                }
+            })
+            .collect();
+        groups.push(people.join("\n"));
+    }
+
+    groups.join("\n\n") + "\n"
+}
+
+/// The number of levels the bags of day 07 are nested in, so unpacking them stays affordable.
+const BAG_LEVELS: usize = 6;
+
+/// Bag rules, where bags only contain bags of the next level. The shiny gold bag is on the third
+/// level, so there are bags around it and inside it.
+pub fn day07(size: usize) -> String {
+    let mut rng = Rng::new(7);
+    let size = size.max(BAG_LEVELS);
+    let level_len = size / BAG_LEVELS;
+    let colour = |i: usize| {
+        if i == 2 * level_len {
+            "shiny gold".to_owned()
+        } else {
+            format!("shade{} colour{}", i / 10, i % 10)
+        }
+    };
+
+    let mut input = String::new();
+    for i in 0..size {
+        let level = i / level_len;
+        let next_level = (level + 1) * level_len..((level + 2) * level_len).min(size);
+        write!(input, "{} bags contain ", colour(i)).unwrap();
+
+        let num_contained = if next_level.is_empty() {
+            0
+        } else {
+            rng.below(4)
+        };
+        if num_contained == 0 {
+            input.push_str("no other bags.\n");
+            continue;
+        }
+
+        let contained: Vec<String> = (0..num_contained)
+            .map(|_| {
+                let bag = next_level.start + rng.below(next_level.len() as u64) as usize;
+                let amount = 1 + rng.below(4);
+                let plural = if amount == 1 { "bag" } else { "bags" };
+                format!("{} {} {}", amount, colour(bag), plural)
+            })
+            .collect();
+        writeln!(input, "{}.", contained.join(", ")).unwrap();
+    }
+
+    input
+}
+
+/// A program that runs through all instructions and then jumps back to the start. Only turning the
+/// last jump into a no-op ends the program, so fixing it has to try every instruction.
+pub fn day08(size: usize) -> String {
+    let mut rng = Rng::new(8);
+    let size = size.max(2);
+    let mut input = String::new();
+    let mut i = 0;
+    while i < size - 1 {
+        let skip = 1 + rng.below(3) as usize;
+        if rng.below(4) == 0 && i + skip < size {
+            writeln!(input, "jmp +{}", skip).unwrap();
+            // Fill the instructions that are jumped over, they are never run.
+            for _ in 1..skip {
+                writeln!(input, "acc -99").unwrap();
+            }
+            i += skip;
+        } else if rng.below(3) == 0 {
+            // Switching this no-op to a jump only loops on itself.
+            writeln!(input, "nop +0").unwrap();
+            i += 1;
+        } else {
+            writeln!(input, "acc {:+}", rng.below(100) as i64 - 50).unwrap();
+            i += 1;
+        }
+    }
+    writeln!(input, "jmp -{}", i).unwrap();
+
+    input
+}
+
+/// The longest sequence day 09 can generate before the numbers would not fit into a `u64`
+/// anymore.
+const MAX_XMAS_LEN: usize = 1500;
+
+/// Numbers where every number is the sum of the two smallest different numbers of the `CHECK_LEN` before
+/// it, except the last one. That one is the sum of the first three numbers instead.
+pub fn day09(size: usize) -> String {
+    let mut rng = Rng::new(9);
+    let mut numbers: Vec<u64> = (1..=CHECK_LEN as u64).collect();
+    for i in (1..numbers.len()).rev() {
+        numbers.swap(i, rng.below(i as u64 + 1) as usize);
+    }
+
+    while numbers.len() < size.clamp(CHECK_LEN + 1, MAX_XMAS_LEN) {
+        let mut window = numbers[numbers.len() - CHECK_LEN..].to_vec();
+        window.sort_unstable();
+        window.dedup();
+        numbers.push(window[0] + window[1]);
+    }
+    numbers.push(numbers[..3].iter().sum());
+
+    numbers
+        .iter()
+        .map(|number| format!("{}\n", number))
+        .collect()
+}
+
+/// Adapters with joltages that are one to three jolts apart, in random order.
+pub fn day10(size: usize) -> String {
+    let mut rng = Rng::new(10);
+    let mut joltage = 0;
+    let mut adapters: Vec<u64> = (0..size)
+        .map(|_| {
+            joltage += 1 + rng.below(3);
+            joltage
+        })
+        .collect();
+    for i in (1..adapters.len()).rev() {
+        adapters.swap(i, rng.below(i as u64 + 1) as usize);
+    }
+
+    adapters
+        .iter()
+        .map(|adapter| format!("{}\n", adapter))
+        .collect()
+}
+
+/// The number of generations after which a generated seat layout counts as never settling.
+const MAX_SEAT_GENERATIONS: usize = 1000;
+
+/// The number of seat layouts day 11 draws before giving up on finding one that settles.
+const MAX_SEAT_LAYOUTS: usize = 100;
+
+/// A seat layout 100 tiles wide, where a quarter of the tiles are floor. Some layouts make the
+/// seats oscillate forever, so layouts are drawn until one settles under the rules of both parts.
+pub fn day11(size: usize) -> String {
+    let mut rng = Rng::new(11);
+    for _ in 0..MAX_SEAT_LAYOUTS {
+        let input = seat_layout(&mut rng, size);
+        let map: TileMap = input.parse().unwrap();
+        let settles = |rule| {
+            matches!(
+                Seating::new(&map, rule).simulate(&map, MAX_SEAT_GENERATIONS),
+                Settlement::FixedPoint { .. }
+            )
+        };
+
+        if settles(RULE_A) && settles(RULE_B) {
+            return input;
+        }
+    }
+
+    panic!("no seat layout of size {} settles", size);
+}
+
+fn seat_layout(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::with_capacity(101 * size);
+    for _ in 0..size {
+        for _ in 0..100 {
+            input.push(if rng.below(4) == 0 { '.' } else { 'L' });
+        }
+        input.push('\n');
+    }
+
+    input
+}
+
+/// Navigation instructions in all directions.
+pub fn day12(size: usize) -> String {
+    let mut rng = Rng::new(12);
+    let mut input = String::new();
+    for _ in 0..size {
+        match rng.below(7) {
+            0..=3 => {
+                let action = rng.choose(&['N', 'S', 'E', 'W', 'F', 'F']);
+                writeln!(input, "{}{}", action, 1 + rng.below(99)).unwrap();
+            }
+            4 | 5 => writeln!(input, "F{}", 1 + rng.below(99)).unwrap(),
+            _ => {
+                let action = rng.choose(&['L', 'R']);
+                writeln!(input, "{}{}", action, 90 * (1 + rng.below(3))).unwrap();
+            }
+        }
+    }
+
+    input
+}
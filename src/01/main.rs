@@ -1,53 +1,25 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use advent2020::day01::Day01;
+use advent2020::input::InputSource;
+use advent2020::runner::Part;
+use advent2020::{Error, Solution};
+use std::process;
 
-fn main() {
-    let file = File::open("input/01").expect("Could not open input file");
-    let reader = BufReader::new(file);
-
-    // Read the file line by line into unsigned integer values as puzzle input.
-
-    let expenses: Vec<u32> = reader
-        .lines()
-        .map(|line| {
-            let line = line.expect("Failure while reading file");
-            line.trim()
-                .parse::<u32>()
-                .expect("Could not parse puzzle input into u32")
-        })
-        .collect();
-
-    // Try all possible combinations of two elements in the vector ------------
+fn run() -> Result<(), Error> {
+    let input = InputSource::from_env_args(1).read()?;
+    let expenses = Day01::parse(&input)?;
 
-    {
-        let mut result = None;
-        for i in 0..expenses.len() {
-            for j in i + 1..expenses.len() {
-                if expenses[i] + expenses[j] == 2020 {
-                    result = Some(expenses[i] * expenses[j]);
-                }
-            }
-        }
+    let result = Day01::part_a(&expenses).ok_or(Error::NoSolution(Part::A))?;
+    println!("The result for 01.a is: {}", result);
 
-        let result = result.expect("Could not find a result for the first exercise.");
-        println!("The result for 01.a is: {}", result);
-    }
-
-    // Do the same but for three numbers in part b) ---------------------------
+    let result = Day01::part_b(&expenses).ok_or(Error::NoSolution(Part::B))?;
+    println!("The result for 01.b is: {}", result);
 
-    {
-        let mut result = None;
-        for i in 0..expenses.len() {
-            for j in i + 1..expenses.len() {
-                for k in j + 1..expenses.len() {
-                    if expenses[i] + expenses[j] + expenses[k] == 2020 {
-                        result = Some(expenses[i] * expenses[j] * expenses[k]);
-                    }
-                }
-            }
-        }
+    Ok(())
+}
 
-        let result = result.expect("Could not find a result for 01.b.");
-        println!("The result for 01.b is: {}", result);
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("{}", err);
+        process::exit(1);
     }
 }
@@ -1,63 +1,28 @@
-use std::fs;
-use std::ops::Range;
+use advent2020::day09::{self, Day09};
+use advent2020::input::InputSource;
+use advent2020::runner::Part;
+use advent2020::{Error, Solution};
+use std::process;
 
-const CHECK_LEN: usize = 25;
+fn run() -> Result<(), Error> {
+    let input = InputSource::from_env_args(9).read()?;
+    let input = Day09::parse(&input)?;
 
-fn num_two_sums(numbers: &[u64], sum: u64) -> usize {
-    let mut count_two_sums = 0;
-    for i in 0..numbers.len() {
-        for j in i + 1..numbers.len() {
-            if numbers[i] != numbers[j] && numbers[i] + numbers[j] == sum {
-                count_two_sums += 1;
-            }
-        }
-    }
+    let first_broken = Day09::part_a(&input).ok_or(Error::NoSolution(Part::A))?;
+    println!("First broken number for a) {}", first_broken);
+
+    let found_set = day09::find_sum_set(&input, first_broken).ok_or(Error::NoSolution(Part::B))?;
+    println!("Found set: {:?}", &input[found_set.clone()]);
+    println!("Found set with size {}", found_set.len());
+    let sum = Day09::part_b(&input).ok_or(Error::NoSolution(Part::B))?;
+    println!("Summing set lowest and biggest together for b) {}", sum);
 
-    count_two_sums
+    Ok(())
 }
 
 fn main() {
-    let input = fs::read_to_string("input/09").expect("Unable to open input file");
-    let input: Vec<u64> = input
-        .lines()
-        .map(|line| {
-            line.parse::<u64>()
-                .expect("Could not parse a u64 from a line")
-        })
-        .collect();
-
-    let mut first_broken = None;
-    for i in CHECK_LEN..input.len() {
-        if num_two_sums(&input[(i - CHECK_LEN)..i], input[i]) == 0 {
-            first_broken = Some(input[i]);
-            break;
-        }
+    if let Err(err) = run() {
+        eprintln!("{}", err);
+        process::exit(1);
     }
-
-    let first_broken = first_broken.expect("No broken number was found");
-    println!("First broken number for a) {}", first_broken);
-
-    let mut set_size = 2;
-    let mut found_set: Option<Range<usize>> = None;
-    'algo: while set_size < input.len() {
-        for i in 0..(input.len() - set_size) {
-            if input[i..i + set_size].iter().sum::<u64>() == first_broken {
-                println!("Found set: {:?}", &input[i..i + set_size]);
-                found_set = Some(i..i + set_size);
-                break 'algo;
-            }
-        }
-
-        set_size += 1;
-    }
-
-    let found_set = found_set.expect("Could not find a matching set");
-    let max = input[found_set.clone()].iter().max().unwrap();
-    let min = input[found_set].iter().min().unwrap();
-
-    println!("Found set with size {}", set_size);
-    println!(
-        "Summing set lowest and biggest together for b) {}",
-        min + max
-    );
 }
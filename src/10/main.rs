@@ -0,0 +1,30 @@
+use advent2020::day10::Day10;
+use advent2020::input::InputSource;
+use advent2020::runner::Part;
+use advent2020::{Error, Solution};
+use std::process;
+
+fn run() -> Result<(), Error> {
+    let input = InputSource::from_env_args(10).read()?;
+    let chain = Day10::parse(&input)?;
+
+    let distribution = Day10::part_a(&chain).ok_or(Error::NoSolution(Part::A))?;
+    println!(
+        "One jolt differences multiplied by three jolt differences for a) {}",
+        distribution
+    );
+    let arrangements = Day10::part_b(&chain).ok_or(Error::NoSolution(Part::B))?;
+    println!(
+        "Number of distinct adapter arrangements for b) {}",
+        arrangements
+    );
+
+    Ok(())
+}
+
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("{}", err);
+        process::exit(1);
+    }
+}
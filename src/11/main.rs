@@ -1,232 +1,164 @@
-use std::fs;
-use std::mem;
-use std::str::FromStr;
-
-pub const DIRECTIONS: [(isize, isize); 8] = [
-    (-1, -1),
-    (-1, 0),
-    (-1, 1),
-    (0, -1),
-    (0, 1),
-    (1, -1),
-    (1, 0),
-    (1, 1),
-];
-
-#[derive(Copy, Clone, PartialEq, Eq)]
-enum Tile {
-    Floor,
-    SeatEmpty,
-    SeatTaken,
+use advent2020::day11::report::SeatReport;
+use advent2020::day11::{
+    render, Day11, Seating, SeatingRule, Settlement, TileMap, MAX_GENERATIONS, RULE_A, RULE_B,
+};
+use advent2020::input::InputSource;
+use advent2020::runner::Part;
+use advent2020::{Error, Solution};
+use std::env;
+use std::path::PathBuf;
+use std::process;
+use std::thread;
+use std::time::Duration;
+
+const USAGE: &str = "usage: 11 [--animate] [--ppm <dir>] [--report] [input|-]";
+
+/// How long every generation is shown when animating in the terminal.
+const FRAME_DELAY: Duration = Duration::from_millis(150);
+
+/// The most generations drawn in the terminal, so animating a map that takes long to settle still
+/// ends in reasonable time.
+const MAX_ANIMATION_FRAMES: usize = 200;
+
+/// The number of pixels on each side of a tile in the PPM images.
+const PPM_SCALE: usize = 4;
+
+/// What the user requested on the command line.
+struct Options {
+    input: InputSource,
+    /// Draw every generation to the terminal before printing the answers.
+    animate: bool,
+    /// Write every generation as image into this directory.
+    ppm_dir: Option<PathBuf>,
+    /// Print statistics about the seats once they are stable.
+    report: bool,
 }
 
-#[derive(Clone, PartialEq)]
-struct TileMap {
-    tiles: Vec<Vec<Tile>>,
-}
-
-impl Tile {
-    pub fn from_char(c: char) -> Self {
-        match c {
-            '.' => Self::Floor,
-            'L' => Self::SeatEmpty,
-            '#' => Self::SeatTaken,
-            o => panic!("Character '{}' is not a tile.", o),
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options {
+        input: InputSource::Day(11),
+        animate: false,
+        ppm_dir: None,
+        report: false,
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--animate" => options.animate = true,
+            "--report" => options.report = true,
+            "--ppm" => match args.next() {
+                Some(dir) => options.ppm_dir = Some(PathBuf::from(dir)),
+                None => return Err("--ppm requires a value".to_owned()),
+            },
+            other if other.starts_with("--") => return Err(format!("unknown option {}", other)),
+            input => options.input = InputSource::from_arg(input),
         }
     }
 
-    pub fn occupied(&self) -> bool {
-        match &self {
-            &Self::SeatTaken => true,
-            _ => false,
-        }
-    }
+    Ok(options)
 }
 
-impl TileMap {
-    pub fn with_size((width, height): (usize, usize)) -> Self {
-        Self {
-            tiles: vec![vec![Tile::Floor; width]; height],
-        }
+/// The number of generations worth drawing: Up to the stable one, or up to the end of the first
+/// cycle, after which the generations only repeat.
+fn num_frames(settlement: &Settlement<TileMap>) -> usize {
+    match settlement {
+        Settlement::FixedPoint { generations, .. } => generations + 1,
+        Settlement::Cycle { start, length, .. } => start + length,
+        Settlement::LimitReached { .. } => MAX_GENERATIONS + 1,
     }
+}
 
-    pub fn num_occupied(&self) -> usize {
-        let mut count = 0;
-        for row in &self.tiles {
-            for tile in row {
-                if tile.occupied() {
-                    count += 1;
-                }
-            }
+/// Draw the generations of the map in the terminal and write them as images, as requested.
+fn render(
+    map: &TileMap,
+    seating: &Seating,
+    num_frames: usize,
+    part: Part,
+    options: &Options,
+) -> Result<(), Error> {
+    if options.animate {
+        let frames = seating
+            .generations(map)
+            .take(num_frames.min(MAX_ANIMATION_FRAMES));
+        for (generation, map) in frames.enumerate() {
+            print!("{}", render::ansi_frame(&map, generation));
+            thread::sleep(FRAME_DELAY);
         }
-
-        count
     }
 
-    pub fn num_adjacent_occupied(&self, (x, y): (usize, usize)) -> u8 {
-        assert!(self.get((x, y)).is_some());
-
-        // First, create a collection of all positions that are right next to this one.
-        let mut adjacent: Vec<(usize, usize)> = DIRECTIONS
-            .iter()
-            .filter_map(|(dx, dy)| {
-                let x = x as isize + dx;
-                let y = y as isize + dy;
-                if x >= 0 && y >= 0 {
-                    Some((x as usize, y as usize))
-                } else {
-                    None
-                }
-            })
-            .collect();
-
-        // Get the actual seats at that positions, if they exist and then count how many of them
-        // are occupied.
-        adjacent
-            .into_iter()
-            .filter(|&pos| match self.get(pos) {
-                Some(seat) => seat.occupied(),
-                None => false,
-            })
-            .count() as u8
+    if let Some(dir) = &options.ppm_dir {
+        let dir = dir.join(part.to_string());
+        let generations = seating.generations(map).take(num_frames);
+        let count = render::write_ppm_frames(generations, &dir, PPM_SCALE)?;
+        println!("Wrote {} frames for {}) to {}", count, part, dir.display());
     }
 
-    pub fn find_view(&self, (x, y): (usize, usize), (dx, dy): (isize, isize)) -> Option<Tile> {
-        let x = x as isize + dx;
-        let y = y as isize + dy;
-        if x < 0 || y < 0 {
-            return None;
-        }
-        let x = x as usize;
-        let y = y as usize;
+    Ok(())
+}
 
-        match self.get((x, y)) {
-            Some(Tile::Floor) => self.find_view((x, y), (dx, dy)),
-            other => other,
-        }
-    }
+fn simulate(map: &TileMap, rule: SeatingRule, part: Part, options: &Options) -> Result<(), Error> {
+    let seating = Seating::new(map, rule);
+    let settlement = seating.simulate(map, MAX_GENERATIONS);
+    render(map, &seating, num_frames(&settlement), part, options)?;
+
+    match settlement {
+        Settlement::FixedPoint {
+            map: stable,
+            generations,
+        } => {
+            println!(
+                "Number of occupied seats for {}) {}, stable after {} generations",
+                part,
+                stable.num_occupied(),
+                generations
+            );
 
-    pub fn num_los_occupied(&self, (x, y): (usize, usize)) -> u8 {
-        let mut count = 0;
-        for dir in &DIRECTIONS {
-            match self.find_view((x, y), *dir) {
-                Some(Tile::SeatTaken) => count += 1,
-                _ => {}
+            if options.report {
+                if let Some(report) = SeatReport::new(&seating, map, MAX_GENERATIONS) {
+                    println!("{}", report);
+                }
             }
-        }
-
-        count
-    }
-
-    pub fn get(&self, (x, y): (usize, usize)) -> Option<Tile> {
-        match self.tiles.get(y) {
-            Some(row) => row.get(x).copied(),
-            None => None,
-        }
-    }
-
-    pub fn set(&mut self, (x, y): (usize, usize), tile: Tile) {
-        self.tiles[y][x] = tile;
-    }
-
-    pub fn size(&self) -> (usize, usize) {
-        if !self.tiles.is_empty() {
-            (self.tiles[0].len(), self.tiles.len())
-        } else {
-            (0, 0)
-        }
-    }
-}
 
-impl FromStr for TileMap {
-    type Err = ();
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut tiles = Vec::new();
-        for line in s.lines() {
-            let tile_row = line.chars().map(|c| Tile::from_char(c)).collect();
-            tiles.push(tile_row);
+            Ok(())
         }
-
-        Ok(Self { tiles })
-    }
-}
-
-fn perform_step_a(source: &TileMap, target: &mut TileMap) {
-    let (width, height) = source.size();
-    for x in 0..width {
-        for y in 0..height {
-            let tile = source.get((x, y)).expect("Tile does not exist");
-            target.set(
-                (x, y),
-                match (tile, source.num_adjacent_occupied((x, y))) {
-                    (Tile::SeatEmpty, 0) => Tile::SeatTaken,
-                    (Tile::SeatTaken, 4..=8) => Tile::SeatEmpty,
-                    (tile, _) => tile,
-                },
+        Settlement::Cycle { start, length, .. } => {
+            eprintln!(
+                "Seats for {}) repeat every {} generations from generation {} on",
+                part, length, start
             );
+            Err(Error::NoSolution(part))
         }
-    }
-}
-
-fn perform_step_b(source: &TileMap, target: &mut TileMap) {
-    let (width, height) = source.size();
-    for x in 0..width {
-        for y in 0..height {
-            let tile = source.get((x, y)).expect("Tile does not exist");
-            target.set(
-                (x, y),
-                match (tile, source.num_los_occupied((x, y))) {
-                    (Tile::SeatEmpty, 0) => Tile::SeatTaken,
-                    (Tile::SeatTaken, 5..=8) => Tile::SeatEmpty,
-                    (tile, _) => tile,
-                },
+        Settlement::LimitReached { .. } => {
+            eprintln!(
+                "Seats for {}) are still changing after {} generations",
+                part, MAX_GENERATIONS
             );
+            Err(Error::NoSolution(part))
         }
     }
 }
 
-fn main() {
-    let tile_map = fs::read_to_string("input/11").expect("Unable to read input file");
-    let map = TileMap::from_str(&tile_map).expect("Could not parse tile map");
-
-    // Create two maps, where one is the base map for every step and the other is the next one.
-    let mut map_one = map.clone();
-    let mut map_two = TileMap::with_size(map_one.size());
-
-    // The source and target maps are switched on each step, for now the first map is the source
-    // map.
-    {
-        let mut source_map = &mut map_one;
-        let mut target_map = &mut map_two;
-        loop {
-            perform_step_a(source_map, target_map);
-
-            // When we've reached a stable state, stop
-            if *source_map == *target_map {
-                break;
-            }
-
-            mem::swap(&mut source_map, &mut target_map);
-        }
-    }
-
-    println!("Number of occupied seats for a) {}", map_one.num_occupied());
+fn run(options: &Options) -> Result<(), Error> {
+    let tile_map = options.input.read()?;
+    let map = Day11::parse(&tile_map)?;
 
-    map_one = map;
-    {
-        let mut source_map = &mut map_one;
-        let mut target_map = &mut map_two;
-        loop {
-            perform_step_b(source_map, target_map);
+    simulate(&map, RULE_A, Part::A, options)?;
+    simulate(&map, RULE_B, Part::B, options)?;
 
-            // When we've reached a stable state, stop
-            if *source_map == *target_map {
-                break;
-            }
+    Ok(())
+}
 
-            mem::swap(&mut source_map, &mut target_map);
+fn main() {
+    let options = match parse_args(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("{}\n{}", err, USAGE);
+            process::exit(2);
         }
-    }
+    };
 
-    println!("Number of occupied seats for b) {}", map_one.num_occupied());
+    if let Err(err) = run(&options) {
+        eprintln!("{}", err);
+        process::exit(1);
+    }
 }
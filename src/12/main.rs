@@ -1,90 +1,70 @@
-use std::f64::consts::PI;
-use std::fs;
-use std::str::FromStr;
+use advent2020::day12::{self, Day12};
+use advent2020::input::InputSource;
+use advent2020::runner::Part;
+use advent2020::{Error, Solution};
+use std::env;
+use std::process;
 
-#[derive(Copy, Clone)]
-pub enum CourseCommand {
-    DeltaLatitude(i32),
-    DeltaLongitude(i32),
-    Turn(f64),
-    Forward(u16),
-}
+const USAGE: &str = "usage: 12 [--continuous] [input|-]";
 
-pub struct Ship {
-    latitude: i32,
-    longitude: i32,
-    heading: f64,
+/// What the user requested on the command line.
+struct Options {
+    input: InputSource,
+    /// Navigate the course continuously, allowing turns by any angle.
+    continuous: bool,
 }
 
-impl Ship {
-    pub fn pos(&self) -> (i32, i32) {
-        (self.latitude, self.longitude)
-    }
+fn parse_args(args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options {
+        input: InputSource::Day(12),
+        continuous: false,
+    };
 
-    pub fn set_course(&mut self, command: CourseCommand) {
-        match command {
-            CourseCommand::DeltaLatitude(dlat) => self.latitude += dlat,
-            CourseCommand::DeltaLongitude(dlong) => self.longitude += dlong,
-            CourseCommand::Turn(rad) => self.heading += rad,
-            CourseCommand::Forward(amount) => {
-                self.latitude -= (self.heading.cos() * amount as f64) as i32;
-                self.longitude += (self.heading.sin() * amount as f64) as i32;
-            }
+    for arg in args {
+        match arg.as_str() {
+            "--continuous" => options.continuous = true,
+            other if other.starts_with("--") => return Err(format!("unknown option {}", other)),
+            input => options.input = InputSource::from_arg(input),
         }
     }
-}
 
-impl Default for Ship {
-    fn default() -> Self {
-        Self {
-            latitude: 0,
-            longitude: 0,
-            heading: 1.5 * PI,
-        }
-    }
+    Ok(options)
 }
 
-impl FromStr for CourseCommand {
-    type Err = ();
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (word, attribute) = s.split_at(1);
-        let attribute = attribute
-            .parse::<u16>()
-            .expect("Unable to read value for command");
+fn run(options: &Options) -> Result<(), Error> {
+    let commands = options.input.read()?;
 
-        match word {
-            "N" => Ok(Self::DeltaLatitude(-(attribute as i32))),
-            "S" => Ok(Self::DeltaLatitude(attribute as i32)),
-            "E" => Ok(Self::DeltaLongitude(-(attribute as i32))),
-            "W" => Ok(Self::DeltaLongitude(attribute as i32)),
-            "L" => Ok(Self::Turn(attribute as f64 / 360. * 2. * PI)),
-            "R" => Ok(Self::Turn(-(attribute as f64 / 360. * 2. * PI))),
-            "F" => Ok(Self::Forward(attribute)),
-            o => panic!("{} is not a valid course word", o),
-        }
+    if options.continuous {
+        let commands = day12::parse_continuous(&commands)?;
+        let distance = day12::continuous_distance(&commands);
+        println!(
+            "Manhattan distance from start to end navigating continuously {}",
+            distance
+        );
+        return Ok(());
     }
-}
 
-#[inline]
-pub fn manhattan_distance(pos1: (i32, i32), pos2: (i32, i32)) -> i32 {
-    (pos1.0 - pos2.0).abs() + (pos1.1 - pos2.0).abs()
+    let commands = Day12::parse(&commands)?;
+
+    let distance = Day12::part_a(&commands).ok_or(Error::NoSolution(Part::A))?;
+    println!("Manhattan distance from start to end for a) {}", distance);
+    let distance = Day12::part_b(&commands).ok_or(Error::NoSolution(Part::B))?;
+    println!("Manhattan distance from start to end for b) {}", distance);
+
+    Ok(())
 }
 
 fn main() {
-    let commands = fs::read_to_string("input/12").expect("Could not read input");
-    let commands: Vec<CourseCommand> = commands
-        .lines()
-        .map(|line| CourseCommand::from_str(line).unwrap())
-        .collect();
+    let options = match parse_args(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("{}\n{}", err, USAGE);
+            process::exit(2);
+        }
+    };
 
-    let mut ship = Ship::default();
-    let start_pos = ship.pos();
-    for command in commands {
-        ship.set_course(command);
+    if let Err(err) = run(&options) {
+        eprintln!("{}", err);
+        process::exit(1);
     }
-
-    println!(
-        "Manhattan distance from start to end for a) {}",
-        manhattan_distance(ship.pos(), start_pos)
-    );
 }
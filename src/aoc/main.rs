@@ -0,0 +1,145 @@
+use advent2020::input::InputSource;
+use advent2020::runner::{self, DayOutcome, Part};
+use advent2020::Error;
+use std::env;
+use std::process;
+
+const USAGE: &str = "usage: aoc run <day|all> [--part <a|b>] [--input <path|->]\n\n\
+Input files are read from the directory in AOC_INPUT_DIR (default: input) unless --input is given,\n\
+where - reads the input from stdin.";
+
+/// What the user requested to be run.
+enum Command {
+    Day {
+        day: u8,
+        part: Option<Part>,
+        input: Option<InputSource>,
+    },
+    All,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    match args.next().as_deref() {
+        Some("run") => {}
+        Some(other) => return Err(format!("unknown command {}", other)),
+        None => return Err("no command given".to_owned()),
+    }
+
+    let day = match args.next() {
+        Some(day) => day,
+        None => return Err("no day given".to_owned()),
+    };
+
+    let mut part = None;
+    let mut input = None;
+    while let Some(arg) = args.next() {
+        let value = match args.next() {
+            Some(value) => value,
+            None => return Err(format!("{} requires a value", arg)),
+        };
+
+        match arg.as_str() {
+            "--part" | "-p" => part = Some(value.parse::<Part>()?),
+            "--input" | "-i" => input = Some(InputSource::from_arg(&value)),
+            other => return Err(format!("unknown option {}", other)),
+        }
+    }
+
+    if day == "all" {
+        if part.is_some() || input.is_some() {
+            return Err("options cannot be used when running all days".to_owned());
+        }
+
+        return Ok(Command::All);
+    }
+
+    match day.parse::<u8>() {
+        Ok(day) => Ok(Command::Day { day, part, input }),
+        Err(_) => Err(format!("{} is not a day", day)),
+    }
+}
+
+fn run_day(day: u8, parts: &[Part], input: &InputSource) -> Result<DayOutcome, String> {
+    let runner = match runner::find_day(day) {
+        Some(runner) => runner,
+        None => return Err(format!("day {:02} has no solution", day)),
+    };
+
+    let input = input.read().map_err(|err| Error::from(err).to_string())?;
+    runner(&input, parts).map_err(|err| err.to_string())
+}
+
+fn run_all() {
+    println!(
+        "{:>3}  {:>20}  {:>20}  {:>12}",
+        "day", "part a", "part b", "time"
+    );
+
+    for (day, _) in &runner::DAYS {
+        match run_day(*day, &Part::ALL, &InputSource::Day(*day)) {
+            Ok(outcome) => {
+                let answers: Vec<String> = outcome
+                    .parts
+                    .iter()
+                    .map(|part| match &part.answer {
+                        Some(answer) => answer.clone(),
+                        None => "-".to_owned(),
+                    })
+                    .collect();
+                let time = outcome.parse_time + outcome.parts.iter().map(|part| part.time).sum();
+
+                println!(
+                    "{:>3}  {:>20}  {:>20}  {:>12}",
+                    format!("{:02}", day),
+                    answers[0],
+                    answers[1],
+                    format!("{:?}", time)
+                );
+            }
+            Err(err) => println!("{:>3}  {}", format!("{:02}", day), err),
+        }
+    }
+}
+
+fn main() {
+    let command = match parse_args(env::args().skip(1)) {
+        Ok(command) => command,
+        Err(err) => {
+            eprintln!("{}\n{}", err, USAGE);
+            process::exit(2);
+        }
+    };
+
+    match command {
+        Command::All => run_all(),
+        Command::Day { day, part, input } => {
+            let parts = match part {
+                Some(part) => vec![part],
+                None => Part::ALL.to_vec(),
+            };
+            let input = input.unwrap_or(InputSource::Day(day));
+
+            let outcome = match run_day(day, &parts, &input) {
+                Ok(outcome) => outcome,
+                Err(err) => {
+                    eprintln!("{}", err);
+                    process::exit(1);
+                }
+            };
+
+            println!("Parsed input for {:02} in {:?}", day, outcome.parse_time);
+            for part in outcome.parts {
+                match part.answer {
+                    Some(answer) => println!(
+                        "The result for {:02}.{} is: {} ({:?})",
+                        day, part.part, answer, part.time
+                    ),
+                    None => println!(
+                        "There is no result for {:02}.{} ({:?})",
+                        day, part.part, part.time
+                    ),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,203 @@
+//! Cellular automatons on any space of cells, as long as it is known which cells are next to each
+//! other. The seats of day 11 are one of them: `day11::SeatSpace` describes them as such a space,
+//! and `day11::Seating` finds the neighbours of every seat through it. `SparseAutomaton` runs on
+//! the same spaces, which also includes grids of hexagons and grids with any number of dimensions.
+
+use std::collections::{BTreeSet, HashMap};
+use std::hash::Hash;
+use std::mem;
+
+use crate::vector::Vec2;
+
+/// A space of cells, describing which cells are neighbours of each other.
+pub trait Neighbourhood {
+    type Cell: Copy + Ord + Hash;
+
+    /// The cells next to the cell, not including the cell itself. Cells must be neighbours of
+    /// each other both ways.
+    fn neighbours(&self, cell: Self::Cell) -> Vec<Self::Cell>;
+}
+
+/// A grid of hexagons in axial coordinates, where every hexagon has six neighbours.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Hexagonal;
+
+impl Hexagonal {
+    pub const DIRECTIONS: [Vec2<i64>; 6] = [
+        Vec2::new(1, 0),
+        Vec2::new(1, -1),
+        Vec2::new(0, -1),
+        Vec2::new(-1, 0),
+        Vec2::new(-1, 1),
+        Vec2::new(0, 1),
+    ];
+}
+
+impl Neighbourhood for Hexagonal {
+    type Cell = Vec2<i64>;
+
+    fn neighbours(&self, cell: Self::Cell) -> Vec<Self::Cell> {
+        Self::DIRECTIONS.iter().map(|&dir| cell + dir).collect()
+    }
+}
+
+/// A grid with `N` dimensions, where every cell is next to all cells that differ by at most one
+/// in every coordinate. In two dimensions these are the eight cells around a seat in day 11.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Moore<const N: usize>;
+
+impl<const N: usize> Neighbourhood for Moore<N> {
+    type Cell = [i64; N];
+
+    fn neighbours(&self, cell: Self::Cell) -> Vec<Self::Cell> {
+        // Count through all 3^N offsets, where every digit in base three is the offset along one
+        // axis plus one.
+        let count = 3usize.pow(N as u32);
+        let mut neighbours = Vec::with_capacity(count - 1);
+        for digits in 0..count {
+            let mut neighbour = cell;
+            let mut rest = digits;
+            for coordinate in &mut neighbour {
+                *coordinate += (rest % 3) as i64 - 1;
+                rest /= 3;
+            }
+
+            if neighbour != cell {
+                neighbours.push(neighbour);
+            }
+        }
+
+        neighbours
+    }
+}
+
+/// How a simulation ended.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Settlement<M> {
+    /// The map stopped changing after the number of generations.
+    FixedPoint { map: M, generations: usize },
+    /// The map keeps repeating the generations from `start` on, every `length` generations. The
+    /// map is the one of generation `start`.
+    Cycle { map: M, start: usize, length: usize },
+    /// The map has neither become stable nor repeated itself before the generation limit. The map
+    /// is the last generation computed.
+    LimitReached { map: M },
+}
+
+/// Perform steps on the map until a generation repeats an earlier one, or until
+/// `max_generations` steps have been performed without that happening.
+pub fn settle<M, F>(map: M, max_generations: usize, mut step: F) -> Settlement<M>
+where
+    M: Clone + Eq + Hash,
+    F: FnMut(&M) -> M,
+{
+    // Every generation seen so far and when it was seen.
+    let mut seen = HashMap::new();
+    let mut current = map;
+    let mut generation = 0;
+
+    loop {
+        if let Some(&start) = seen.get(&current) {
+            let length = generation - start;
+            return if length == 1 {
+                Settlement::FixedPoint {
+                    map: current,
+                    generations: start,
+                }
+            } else {
+                Settlement::Cycle {
+                    map: current,
+                    start,
+                    length,
+                }
+            };
+        }
+
+        if generation == max_generations {
+            return Settlement::LimitReached { map: current };
+        }
+
+        let next = step(&current);
+        seen.insert(mem::replace(&mut current, next), generation);
+        generation += 1;
+    }
+}
+
+/// An automaton that only stores the occupied cells, so the space does not need any bounds.
+///
+/// The rule decides whether a cell is occupied in the next generation, given whether it is
+/// occupied now and how many of its neighbours are.
+pub struct SparseAutomaton<N: Neighbourhood, R> {
+    neighbourhood: N,
+    rule: R,
+    /// The cells that can be occupied at all, or `None` if every cell can.
+    seats: Option<BTreeSet<N::Cell>>,
+}
+
+impl<N, R> SparseAutomaton<N, R>
+where
+    N: Neighbourhood,
+    R: Fn(bool, usize) -> bool,
+{
+    /// Create an automaton where every cell can be occupied. Panics if the rule fills empty cells
+    /// without occupied neighbours, since that would fill the whole endless space.
+    pub fn new(neighbourhood: N, rule: R) -> Self {
+        assert!(
+            !rule(false, 0),
+            "the rule must not occupy cells without occupied neighbours in an endless space"
+        );
+
+        Self {
+            neighbourhood,
+            rule,
+            seats: None,
+        }
+    }
+
+    /// Create an automaton where only the seats can be occupied, like the floor plans of day 11.
+    pub fn with_seats<I>(neighbourhood: N, rule: R, seats: I) -> Self
+    where
+        I: IntoIterator<Item = N::Cell>,
+    {
+        Self {
+            neighbourhood,
+            rule,
+            seats: Some(seats.into_iter().collect()),
+        }
+    }
+
+    /// The occupied cells of the next generation.
+    pub fn step(&self, occupied: &BTreeSet<N::Cell>) -> BTreeSet<N::Cell> {
+        let mut counts: HashMap<N::Cell, usize> = HashMap::new();
+        for &cell in occupied {
+            for neighbour in self.neighbourhood.neighbours(cell) {
+                *counts.entry(neighbour).or_insert(0) += 1;
+            }
+        }
+
+        let next_occupied = |cell: &N::Cell| {
+            let count = counts.get(cell).copied().unwrap_or(0);
+            (self.rule)(occupied.contains(cell), count)
+        };
+
+        match &self.seats {
+            Some(seats) => seats.iter().copied().filter(next_occupied).collect(),
+            // Only cells that are occupied or next to one can be occupied in the next generation.
+            None => occupied
+                .iter()
+                .chain(counts.keys())
+                .copied()
+                .filter(next_occupied)
+                .collect(),
+        }
+    }
+
+    /// Perform steps until the occupied cells repeat, same as `Seating::simulate`.
+    pub fn simulate(
+        &self,
+        occupied: BTreeSet<N::Cell>,
+        max_generations: usize,
+    ) -> Settlement<BTreeSet<N::Cell>> {
+        settle(occupied, max_generations, |occupied| self.step(occupied))
+    }
+}
@@ -0,0 +1,48 @@
+use crate::error::{self, Error};
+use crate::Solution;
+
+/// The sum the expenses in question must add up to.
+pub const EXPENSE_SUM: u32 = 2020;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<u32>;
+    type AnswerA = u32;
+    type AnswerB = u32;
+
+    // Read the input line by line into unsigned integer values as puzzle input.
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        error::parse_lines(input, |line| line.trim().parse::<u32>())
+    }
+
+    // Try all possible combinations of two elements in the vector
+    fn part_a(expenses: &Self::Input) -> Option<Self::AnswerA> {
+        let mut result = None;
+        for i in 0..expenses.len() {
+            for j in i + 1..expenses.len() {
+                if expenses[i] + expenses[j] == EXPENSE_SUM {
+                    result = Some(expenses[i] * expenses[j]);
+                }
+            }
+        }
+
+        result
+    }
+
+    // Do the same but for three numbers in part b)
+    fn part_b(expenses: &Self::Input) -> Option<Self::AnswerB> {
+        let mut result = None;
+        for i in 0..expenses.len() {
+            for j in i + 1..expenses.len() {
+                for k in j + 1..expenses.len() {
+                    if expenses[i] + expenses[j] + expenses[k] == EXPENSE_SUM {
+                        result = Some(expenses[i] * expenses[j] * expenses[k]);
+                    }
+                }
+            }
+        }
+
+        result
+    }
+}
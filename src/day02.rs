@@ -0,0 +1,155 @@
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+use crate::error::{self, Error};
+use crate::Solution;
+
+/// Structure that describes the policy concerning one specific character inside of a password
+/// employing this policy.
+pub struct CharPolicy {
+    /// The character this policy concerns itself with
+    character: char,
+    /// For the first version, this is the minimum number of occurences as well as the maximum
+    /// number of occurences of the character in question.
+    /// For the second part of the puzzle, this describes the indices that must be checked.
+    accepted_occurences: (usize, usize),
+}
+
+impl CharPolicy {
+    /// Checks if a provided password complies with this policy when interpreted as the policy of
+    /// the first version. Returns true if it does, otherwise false.
+    pub fn check_password_by_a(&self, password: &str) -> bool {
+        // Count the occurences of the important character in the password.
+        let occurences = password.chars().filter(|&c| c == self.character).count();
+
+        // Check that the number of occurences is indeed in the desired range.
+        occurences >= self.accepted_occurences.0 && occurences <= self.accepted_occurences.1
+    }
+
+    /// Checks if a provided password complies with the policy when interpreting it as a policy for
+    /// the second part of the puzzle, the 'new' policy.
+    pub fn check_password_by_b(&self, password: &str) -> bool {
+        // The indices are one-based, so there is no character at index zero.
+        let is_occ = |index: usize| match index.checked_sub(1) {
+            Some(index) => password.chars().nth(index) == Some(self.character),
+            None => false,
+        };
+        let first_is_occ = is_occ(self.accepted_occurences.0);
+        let second_is_occ = is_occ(self.accepted_occurences.1);
+
+        first_is_occ ^ second_is_occ
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CharPolicyParseErr {
+    #[error("expected one range and one character part, found {0} parts")]
+    PartCount(usize),
+    #[error("cannot use multiple characters per character policy")]
+    MultipleChars,
+    #[error("range can only be constructed from 2 items, but {0} were provided")]
+    RangeLength(usize),
+    #[error("unable to parse range bound: {0}")]
+    RangeBound(#[from] ParseIntError),
+    #[error("minimum {0} must not be greater than the maximum {1} to create a range")]
+    InvertedRange(usize, usize),
+    #[error("line does not contain two parts, a rule and a password")]
+    MissingPassword,
+}
+
+impl FromStr for CharPolicy {
+    type Err = CharPolicyParseErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Split the range from the character information part
+        let parts: Vec<&str> = s.split_whitespace().collect();
+        if parts.len() != 2 {
+            return Err(Self::Err::PartCount(parts.len()));
+        }
+
+        let range_str = parts[0];
+        let char_str = parts[1];
+
+        // Check that it's only one char, not more
+        let mut chars = char_str.chars();
+        let character = match (chars.next(), chars.next()) {
+            (Some(character), None) => character,
+            _ => return Err(Self::Err::MultipleChars),
+        };
+
+        // Process the string containing the range
+        let range_parts: Vec<&str> = range_str.split('-').collect();
+        if range_parts.len() != 2 {
+            return Err(Self::Err::RangeLength(range_parts.len()));
+        }
+
+        let accepted_occurences = {
+            let min = range_parts[0].parse()?;
+            let max = range_parts[1].parse()?;
+
+            if min > max {
+                return Err(Self::Err::InvertedRange(min, max));
+            }
+
+            (min, max)
+        };
+
+        Ok(Self {
+            character,
+            accepted_occurences,
+        })
+    }
+}
+
+/// A password as found in the password database, together with the policy that was in effect when
+/// it was created.
+pub struct PasswordEntry {
+    pub policy: CharPolicy,
+    pub password: String,
+}
+
+impl FromStr for PasswordEntry {
+    type Err = CharPolicyParseErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split(':').collect();
+        if parts.len() != 2 {
+            return Err(Self::Err::MissingPassword);
+        }
+
+        Ok(Self {
+            policy: CharPolicy::from_str(parts[0])?,
+            password: parts[1].trim().to_owned(),
+        })
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<PasswordEntry>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        error::parse_lines(input, PasswordEntry::from_str)
+    }
+
+    fn part_a(entries: &Self::Input) -> Option<Self::AnswerA> {
+        Some(
+            entries
+                .iter()
+                .filter(|entry| entry.policy.check_password_by_a(&entry.password))
+                .count(),
+        )
+    }
+
+    fn part_b(entries: &Self::Input) -> Option<Self::AnswerB> {
+        Some(
+            entries
+                .iter()
+                .filter(|entry| entry.policy.check_password_by_b(&entry.password))
+                .count(),
+        )
+    }
+}
@@ -0,0 +1,131 @@
+use std::str::FromStr;
+
+use crate::error::Error;
+use crate::grid::{Grid, Topology};
+use crate::vector::Vec2;
+use crate::Solution;
+
+/// Terrain with a certain height, but with infinite width in positive integer direction.
+pub struct Terrain {
+    /// Contains true wherever a tree is located and false where no tree is. The grid wraps
+    /// horizontally, since the pattern repeats to the right.
+    data: Grid<bool>,
+}
+
+impl Terrain {
+    pub fn height(&self) -> usize {
+        self.data.height()
+    }
+
+    /// Check if there is a tree on this position. If the index is out of bounds returns false.
+    pub fn has_tree(&self, pos: Vec2<usize>) -> bool {
+        self.data.get(pos).copied().unwrap_or(false)
+    }
+
+    /// Count the trees on this terrain when following a certain course.
+    pub fn trees_on_course(&self, delta: Vec2<usize>) -> usize {
+        let mut pos = Vec2::new(0, 0);
+        let mut num_trees = 0;
+        while pos.y < self.height() {
+            if self.has_tree(pos) {
+                num_trees += 1;
+            }
+
+            pos += delta;
+        }
+
+        num_trees
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum TerrainParseError {
+    /// The token that is unknown and its byte offset in the terrain string.
+    #[error("unknown token {0}")]
+    UnknownToken(char, usize),
+    /// A row of the terrain has no tiles, so it cannot be repeated. Contains the byte offset of
+    /// the row in the terrain string.
+    #[error("row is empty")]
+    EmptyRow(usize),
+    /// The width of the first row, the width of the offending row and its byte offset in the
+    /// terrain string.
+    #[error("all rows must be {0} tiles wide, but this one has {1}")]
+    RowLength(usize, usize, usize),
+}
+
+impl FromStr for Terrain {
+    type Err = TerrainParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut data = Vec::with_capacity(s.len());
+        let mut width = None;
+        let mut height = 0;
+        for line in s.lines() {
+            let line_offset = line.as_ptr() as usize - s.as_ptr() as usize;
+            if line.is_empty() {
+                return Err(Self::Err::EmptyRow(line_offset));
+            }
+
+            let row_start = data.len();
+            for (i, c) in line.char_indices() {
+                data.push(match c {
+                    '.' => false,
+                    '#' => true,
+                    other => return Err(Self::Err::UnknownToken(other, line_offset + i)),
+                });
+            }
+
+            let row_len = data.len() - row_start;
+            match width {
+                Some(width) if width != row_len => {
+                    return Err(Self::Err::RowLength(width, row_len, line_offset))
+                }
+                _ => width = Some(row_len),
+            }
+
+            height += 1;
+        }
+
+        Ok(Self {
+            data: Grid::from_cells(data, (width.unwrap_or(0), height), Topology::WrapHorizontal),
+        })
+    }
+}
+
+/// The course that must be followed for a), given as the step taken each time.
+pub const COURSE_A: Vec2<usize> = Vec2::new(3, 1);
+
+/// All courses that must be plotted for b), given as the step taken each time.
+pub const COURSES_B: [Vec2<usize>; 5] = [
+    Vec2::new(3, 1),
+    Vec2::new(1, 1),
+    Vec2::new(5, 1),
+    Vec2::new(7, 1),
+    Vec2::new(1, 2),
+];
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Terrain;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Terrain::from_str(input).map_err(|err| Error::parse(input, input, err))
+    }
+
+    fn part_a(terrain: &Self::Input) -> Option<Self::AnswerA> {
+        Some(terrain.trees_on_course(COURSE_A))
+    }
+
+    // The number of trees encountered for each course multiplied is the solution for b)
+    fn part_b(terrain: &Self::Input) -> Option<Self::AnswerB> {
+        Some(
+            COURSES_B
+                .iter()
+                .map(|&course| terrain.trees_on_course(course))
+                .product(),
+        )
+    }
+}
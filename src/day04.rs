@@ -0,0 +1,195 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use crate::error::Error;
+use crate::Solution;
+
+pub const BIRTH_YEAR: &str = "byr";
+pub const ISSUE_YEAR: &str = "iyr";
+pub const EXP_YEAR: &str = "eyr";
+pub const HEIGHT: &str = "hgt";
+pub const HAIRCOLOUR: &str = "hcl";
+pub const EYECOLOUR: &str = "ecl";
+pub const PASS_ID: &str = "pid";
+
+pub const COUNTRY_ID: &str = "cid";
+
+pub const PASSPORT_REQUIRED_FIELDS: [&str; 7] = [
+    BIRTH_YEAR, ISSUE_YEAR, EXP_YEAR, HEIGHT, HAIRCOLOUR, EYECOLOUR, PASS_ID,
+];
+
+#[derive(Debug, thiserror::Error)]
+pub enum PassportParseErr {
+    #[error("missing fields {0}")]
+    MissingField(String),
+    #[error("field must consist of a name and a value, but it has {0} parts")]
+    WrongFieldFormat(usize),
+    #[error("field was found twice: {0}")]
+    DoubledField(String),
+}
+
+pub struct Passport {
+    fields: HashMap<String, String>,
+}
+
+/// The reason a passport with all required fields has invalid values according to the rules in b)
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum PassportValueErr {
+    #[error("birth year invalid: {0}")]
+    BirthYear(String),
+    #[error("issue year invalid: {0}")]
+    IssueYear(String),
+    #[error("expiration year invalid: {0}")]
+    ExpirationYear(String),
+    #[error("height {0} does not end in cm or in")]
+    HeightUnit(String),
+    #[error("could not parse int from height {0}")]
+    HeightValue(String),
+    #[error("{0} not a valid height")]
+    HeightRange(String),
+    #[error("not a valid haircolour: {0}")]
+    HairColour(String),
+    #[error("{0} not a valid eye colour")]
+    EyeColour(String),
+    #[error("passport id must consist of 9 numbers, not {0}")]
+    PassportId(String),
+}
+
+fn check_year(s: &str, min: u32, max: u32) -> bool {
+    match s.parse::<u32>() {
+        Ok(year) => year >= min && year <= max,
+        Err(_) => false,
+    }
+}
+
+impl Passport {
+    /// Checks if all required values are ok according to the rules in b)
+    pub fn values_ok(&self) -> bool {
+        self.check_values().is_ok()
+    }
+
+    /// Checks all required values according to the rules in b) and returns the first one that is
+    /// not ok.
+    pub fn check_values(&self) -> Result<(), PassportValueErr> {
+        let birth_year = &self.fields[BIRTH_YEAR];
+        if !check_year(birth_year, 1920, 2002) {
+            return Err(PassportValueErr::BirthYear(birth_year.clone()));
+        }
+        let issue_year = &self.fields[ISSUE_YEAR];
+        if !check_year(issue_year, 2010, 2020) {
+            return Err(PassportValueErr::IssueYear(issue_year.clone()));
+        }
+        let exp_year = &self.fields[EXP_YEAR];
+        if !check_year(exp_year, 2020, 2030) {
+            return Err(PassportValueErr::ExpirationYear(exp_year.clone()));
+        }
+
+        // Check height
+        let height = &self.fields[HEIGHT];
+        let (value, cm) = if let Some(value) = height.strip_suffix("cm") {
+            (value, true)
+        } else if let Some(value) = height.strip_suffix("in") {
+            (value, false)
+        } else {
+            return Err(PassportValueErr::HeightUnit(height.clone()));
+        };
+        let value = value
+            .parse::<u8>()
+            .map_err(|_| PassportValueErr::HeightValue(height.clone()))?;
+        let height_range = if cm { 150..=193 } else { 59..=76 };
+        if !height_range.contains(&value) {
+            return Err(PassportValueErr::HeightRange(height.clone()));
+        }
+
+        // Check haircolour
+        let haircolour = &self.fields[HAIRCOLOUR];
+        let valid_haircolour = match haircolour.strip_prefix('#') {
+            Some(hex) => hex.len() == 6 && hex.chars().all(|c| matches!(c, '0'..='9' | 'a'..='f')),
+            None => false,
+        };
+        if !valid_haircolour {
+            return Err(PassportValueErr::HairColour(haircolour.clone()));
+        }
+
+        // Check eyecolour
+        match self.fields[EYECOLOUR].as_str() {
+            "amb" | "blu" | "brn" | "gry" | "grn" | "hzl" | "oth" => {}
+            o => return Err(PassportValueErr::EyeColour(o.to_owned())),
+        }
+
+        // Check passport id
+        let passport_id = &self.fields[PASS_ID];
+        if passport_id.len() != 9 || !passport_id.chars().all(|c| c.is_ascii_digit()) {
+            return Err(PassportValueErr::PassportId(passport_id.clone()));
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Passport {
+    type Err = PassportParseErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = HashMap::new();
+
+        let attributes = s.split_whitespace();
+        for attribute in attributes {
+            // Split the attribute (hopefully into two) and add it into the hashmap
+            let field: Vec<&str> = attribute.split(':').collect();
+            if field.len() != 2 {
+                return Err(Self::Err::WrongFieldFormat(field.len()));
+            }
+
+            if fields
+                .insert(field[0].to_owned(), field[1].to_owned())
+                .is_some()
+            {
+                return Err(Self::Err::DoubledField(field[0].to_owned()));
+            }
+        }
+
+        // Check if the passport information is complete
+        for field in &PASSPORT_REQUIRED_FIELDS {
+            if !fields.contains_key(*field) {
+                return Err(Self::Err::MissingField(String::from(*field)));
+            }
+        }
+
+        Ok(Self { fields })
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    /// Passports that are missing fields are kept around with the reason they are invalid.
+    type Input = Vec<Result<Passport, Error>>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(input
+            .split("\n\n")
+            .map(|passport| {
+                Passport::from_str(passport).map_err(|err| Error::parse(input, passport, err))
+            })
+            .collect())
+    }
+
+    fn part_a(passports: &Self::Input) -> Option<Self::AnswerA> {
+        Some(passports.iter().filter(|passport| passport.is_ok()).count())
+    }
+
+    fn part_b(passports: &Self::Input) -> Option<Self::AnswerB> {
+        Some(
+            passports
+                .iter()
+                .filter(|passport| match passport {
+                    Ok(passport) => passport.values_ok(),
+                    Err(_) => false,
+                })
+                .count(),
+        )
+    }
+}
@@ -0,0 +1,136 @@
+use std::cmp;
+use std::ops::Deref;
+use std::str::FromStr;
+
+use crate::error::{self, Error};
+use crate::Solution;
+
+const NUM_ROWS: u8 = 128;
+const NUM_COLS: u8 = 8;
+const NUM_IDS: usize = NUM_ROWS as usize * 8;
+
+pub struct BoardingInfo(Vec<Direction>);
+
+impl Deref for BoardingInfo {
+    type Target = [Direction];
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+pub enum Direction {
+    // Negative x direction
+    Left,
+    // Positive x direction
+    Right,
+    // Negative z direction
+    Front,
+    // Positive z direction
+    Back,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum BoardingInfoParseErr {
+    /// The character that is not a direction and its byte offset in the boarding information.
+    #[error("{0} is not a valid direction")]
+    InvalidDirection(char, usize),
+}
+
+impl FromStr for BoardingInfo {
+    type Err = BoardingInfoParseErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut info = Vec::with_capacity(s.len());
+        for (i, c) in s.char_indices() {
+            let dir = match c {
+                'L' => Direction::Left,
+                'R' => Direction::Right,
+                'F' => Direction::Front,
+                'B' => Direction::Back,
+                o => return Err(Self::Err::InvalidDirection(o, i)),
+            };
+            info.push(dir);
+        }
+
+        Ok(BoardingInfo(info))
+    }
+}
+
+pub fn binary_search_seat(info: &[Direction]) -> (u8, u8) {
+    let mut min_x = 0;
+    let mut min_z = 0;
+    let mut max_x = NUM_COLS;
+    let mut max_z = NUM_ROWS;
+
+    for dir in info {
+        let mid_x = (min_x + max_x) / 2;
+        let mid_z = (min_z + max_z) / 2;
+        match dir {
+            Direction::Left => max_x = mid_x,
+            Direction::Right => min_x = mid_x,
+            Direction::Front => max_z = mid_z,
+            Direction::Back => min_z = mid_z,
+        }
+    }
+
+    (min_x, min_z)
+}
+
+/// Calculate the id of the seat the boarding information points to.
+pub fn seat_id(info: &[Direction]) -> u32 {
+    let (seat_x, seat_z) = binary_search_seat(info);
+    seat_z as u32 * 8 + seat_x as u32
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Vec<BoardingInfo>;
+    type AnswerA = u32;
+    type AnswerB = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        error::parse_lines(input, BoardingInfo::from_str)
+    }
+
+    fn part_a(infos: &Self::Input) -> Option<Self::AnswerA> {
+        let mut max_seat_id = 0;
+        for info in infos {
+            max_seat_id = cmp::max(max_seat_id, seat_id(info));
+        }
+
+        Some(max_seat_id)
+    }
+
+    fn part_b(infos: &Self::Input) -> Option<Self::AnswerB> {
+        let mut available_seats = [true; NUM_IDS];
+        for info in infos {
+            available_seats[seat_id(info) as usize] = false;
+        }
+
+        /* The very first and the very last seats that appear available are just missing. Mark them
+         * as unavailable as well
+         */
+        // First for the front
+        for available in available_seats.iter_mut() {
+            // Abort with the first seat unavailable
+            if !*available {
+                break;
+            }
+            *available = false;
+        }
+        // Then in the back too
+        for available in available_seats.iter_mut().rev() {
+            // Abort with the first seat unavailable
+            if !*available {
+                break;
+            }
+            *available = false;
+        }
+
+        /* Find the one seat not totally at the front and not totally in the back that is not taken
+         * starting with the second row and ending with the second to last row.
+         */
+        available_seats.iter().position(|&available| available)
+    }
+}
@@ -0,0 +1,93 @@
+use crate::error::Error;
+use crate::Solution;
+
+// Only lowercase, standard latin alphabet are allowed.
+pub const NUM_CHARS: usize = 26;
+
+/// The yes answers of one group, counted per question, together with the number of people in it.
+pub type GroupAnswers = ([usize; NUM_CHARS], usize);
+
+#[derive(Debug, thiserror::Error)]
+pub enum AnswersParseErr {
+    /// The character that is not an answer and its byte offset in the group string.
+    #[error("unexpected character: '{0}'")]
+    UnexpectedCharacter(char, usize),
+    /// The answer that was given twice by the same person and its byte offset in the group string.
+    #[error("question {0} was answered twice by the same person")]
+    DoubledAnswer(char, usize),
+}
+
+// Count the number of yes answers of a group. Returns also the number of people in that group for
+// reference.
+pub fn count_yes_answers(s: &str) -> Result<GroupAnswers, AnswersParseErr> {
+    // Trimming is necessary for the last element to be counted properly. Otherwise it thinks there
+    // is one more person in the group.
+    let s = s.trim_end();
+
+    let mut answers = [0; NUM_CHARS];
+    // The answers of the person currently being read, so no one can answer a question twice.
+    let mut person_answers = [false; NUM_CHARS];
+    let mut num_people = 1;
+    for (i, c) in s.char_indices() {
+        match c {
+            'a'..='z' => {
+                let question = c as usize - 'a' as usize;
+                if person_answers[question] {
+                    return Err(AnswersParseErr::DoubledAnswer(c, i));
+                }
+                person_answers[question] = true;
+                answers[question] += 1;
+            }
+            '\n' => {
+                num_people += 1;
+                person_answers = [false; NUM_CHARS];
+            }
+            other => return Err(AnswersParseErr::UnexpectedCharacter(other, i)),
+        }
+    }
+
+    Ok((answers, num_people))
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<GroupAnswers>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input
+            .split("\n\n")
+            .map(|group| count_yes_answers(group).map_err(|err| Error::parse(input, group, err)))
+            .collect()
+    }
+
+    fn part_a(groups: &Self::Input) -> Option<Self::AnswerA> {
+        let mut num_any_yes = 0;
+        for (yes_answers, num_people) in groups {
+            for a in yes_answers.iter() {
+                assert!(*a <= *num_people);
+                if *a >= 1 {
+                    num_any_yes += 1;
+                }
+            }
+        }
+
+        Some(num_any_yes)
+    }
+
+    fn part_b(groups: &Self::Input) -> Option<Self::AnswerB> {
+        let mut num_all_yes = 0;
+        for (yes_answers, num_people) in groups {
+            for a in yes_answers.iter() {
+                assert!(*a <= *num_people);
+                if *a == *num_people {
+                    num_all_yes += 1;
+                }
+            }
+        }
+
+        Some(num_all_yes)
+    }
+}
@@ -0,0 +1,219 @@
+use std::str::{FromStr, SplitWhitespace};
+
+use crate::error::Error;
+use crate::Solution;
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct BagColour {
+    special: String,
+    base: String,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct BagRule {
+    container_colour: BagColour,
+    contains: Vec<(BagColour, usize)>,
+}
+
+impl BagColour {
+    pub fn new(special: String, base: String) -> Self {
+        Self { special, base }
+    }
+}
+
+impl BagRule {
+    pub fn can_contain(&self, (bag_colour, amount): &(BagColour, usize)) -> bool {
+        for (bc, a) in &self.contains {
+            if bc == bag_colour && a >= amount {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    pub fn container_colour(&self) -> &BagColour {
+        &self.container_colour
+    }
+
+    pub fn contained(&self) -> &Vec<(BagColour, usize)> {
+        &self.contains
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum BagRuleParseErr {
+    #[error("expected token {0}, found {1}")]
+    UnexpectedToken(String, String),
+    #[error("expected token of type {0}, which token {1} is not")]
+    TokenType(String, String),
+    #[error("ended in invalid parsing state, string too short")]
+    UnexpectedEOF,
+}
+
+impl FromStr for BagRule {
+    type Err = BagRuleParseErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut words = s.split_whitespace();
+
+        // Helper function to read a token when there must be one. Returns that token or a
+        // returnable error if there is no string given.
+        fn read_token<'a>(words: &mut SplitWhitespace<'a>) -> Result<&'a str, BagRuleParseErr> {
+            match words.next() {
+                Some(s) => Ok(s),
+                None => Err(BagRuleParseErr::UnexpectedEOF),
+            }
+        }
+
+        // Helper to read the next token and check that it is indeed the expected token. Useful
+        // when there can be only one token next but it must exist.
+        fn expect_token<'a>(
+            words: &mut SplitWhitespace<'a>,
+            expected: &str,
+        ) -> Result<(), BagRuleParseErr> {
+            let token = read_token(words)?;
+            if token == expected {
+                Ok(())
+            } else {
+                Err(BagRuleParseErr::UnexpectedToken(
+                    expected.to_owned(),
+                    token.to_owned(),
+                ))
+            }
+        }
+
+        // The first colour will have no number identifier but must exist
+        let container_colour = BagColour::new(
+            read_token(&mut words)?.to_owned(),
+            read_token(&mut words)?.to_owned(),
+        );
+
+        expect_token(&mut words, "bags")?;
+        expect_token(&mut words, "contain")?;
+
+        let mut contains = Vec::new();
+        loop {
+            let amount = read_token(&mut words)?;
+            // Token that declares no other bags will be contained. Check for correct format
+            if amount == "no" {
+                expect_token(&mut words, "other")?;
+                expect_token(&mut words, "bags.")?;
+                break;
+            }
+
+            // Read information about the bag type that can be contained.
+            let amount = match amount.parse::<usize>() {
+                Ok(amount) => amount,
+                Err(_) => return Err(Self::Err::TokenType("usize".to_owned(), amount.to_owned())),
+            };
+            let bag_colour = BagColour::new(
+                read_token(&mut words)?.to_owned(),
+                read_token(&mut words)?.to_owned(),
+            );
+
+            contains.push((bag_colour, amount));
+
+            // Check if there will be other bag types coming or not and abort if not.
+            match (amount, read_token(&mut words)?) {
+                (1, "bag,") | (_, "bags,") => {}     // continue on comma
+                (1, "bag.") | (_, "bags.") => break, // abort on period
+                (_, other) => {
+                    return Err(Self::Err::UnexpectedToken(
+                        "bag continuation".to_owned(),
+                        other.to_owned(),
+                    ))
+                }
+            }
+        }
+
+        Ok(Self {
+            container_colour,
+            contains,
+        })
+    }
+}
+
+/// The bag we are carrying ourselves, one shiny gold bag.
+pub fn own_bag() -> (BagColour, usize) {
+    (BagColour::new("shiny".to_owned(), "gold".to_owned()), 1)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<BagRule>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input
+            .lines()
+            // Ignore empty lines
+            .filter(|rule| !rule.trim().is_empty())
+            .map(|rule| BagRule::from_str(rule).map_err(|err| Error::parse(input, rule, err)))
+            .collect()
+    }
+
+    fn part_a(rules: &Self::Input) -> Option<Self::AnswerA> {
+        let own_bag = own_bag();
+
+        // Mark all rules that can immediately contain our own bag
+        let mut possible_rules: Vec<bool> = rules
+            .iter()
+            .map(|rule| rule.can_contain(&own_bag))
+            .collect();
+
+        let mut added_rules = true;
+        while added_rules {
+            added_rules = false;
+
+            // Add all rules that can contain any of the bags that are able to contain at least one
+            // shiny gold bag.
+            for (i, rule) in rules.iter().enumerate() {
+                // Ignore rules that already have been added
+                if possible_rules[i] {
+                    continue;
+                }
+
+                // Check if any of the rules that have been confirmed by the algorithm to be able
+                // to contain our own bag are contained in the current rule.
+                if possible_rules.iter().enumerate().any(|(i, &possible)| {
+                    possible && rule.can_contain(&(rules[i].container_colour().clone(), 1))
+                }) {
+                    added_rules = true;
+                    possible_rules[i] = true;
+                }
+            }
+        }
+
+        Some(possible_rules.iter().filter(|&possible| *possible).count())
+    }
+
+    fn part_b(rules: &Self::Input) -> Option<Self::AnswerB> {
+        let mut rules = rules.clone();
+        rules.sort();
+
+        let mut current_bags: Vec<(BagColour, usize)> = vec![own_bag()];
+        let mut num_bags = 0;
+        while !current_bags.is_empty() {
+            let mut children = Vec::new();
+            for (colour, amount) in current_bags.drain(..) {
+                // Without a rule for a bag there is no way of knowing what it contains.
+                let pos = rules
+                    .binary_search_by_key(&&colour, |rule| rule.container_colour())
+                    .ok()?;
+
+                for (child_colour, child_amount) in rules[pos].contained() {
+                    let adjusted_amount = amount * child_amount;
+                    num_bags += adjusted_amount;
+                    children.push((child_colour.clone(), adjusted_amount));
+                }
+            }
+
+            current_bags = children;
+        }
+
+        Some(num_bags)
+    }
+}
@@ -0,0 +1,72 @@
+use std::ops::Range;
+
+use crate::error::{self, Error};
+use crate::Solution;
+
+pub const CHECK_LEN: usize = 25;
+
+pub fn num_two_sums(numbers: &[u64], sum: u64) -> usize {
+    let mut count_two_sums = 0;
+    for i in 0..numbers.len() {
+        for j in i + 1..numbers.len() {
+            if numbers[i] != numbers[j] && numbers[i] + numbers[j] == sum {
+                count_two_sums += 1;
+            }
+        }
+    }
+
+    count_two_sums
+}
+
+/// Find the first number that is not the sum of two different numbers of the `CHECK_LEN` numbers
+/// before it.
+pub fn find_first_broken(input: &[u64]) -> Option<u64> {
+    for i in CHECK_LEN..input.len() {
+        if num_two_sums(&input[(i - CHECK_LEN)..i], input[i]) == 0 {
+            return Some(input[i]);
+        }
+    }
+
+    None
+}
+
+/// Find the smallest contiguous set of at least two numbers that sums up to `sum`.
+pub fn find_sum_set(input: &[u64], sum: u64) -> Option<Range<usize>> {
+    let mut set_size = 2;
+    while set_size < input.len() {
+        for i in 0..(input.len() - set_size) {
+            if input[i..i + set_size].iter().sum::<u64>() == sum {
+                return Some(i..i + set_size);
+            }
+        }
+
+        set_size += 1;
+    }
+
+    None
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<u64>;
+    type AnswerA = u64;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        error::parse_lines(input, |line| line.parse::<u64>())
+    }
+
+    fn part_a(input: &Self::Input) -> Option<Self::AnswerA> {
+        find_first_broken(input)
+    }
+
+    // Summing the lowest and biggest number of the set together is the solution for b)
+    fn part_b(input: &Self::Input) -> Option<Self::AnswerB> {
+        let found_set = find_sum_set(input, find_first_broken(input)?)?;
+        let max = input[found_set.clone()].iter().max()?;
+        let min = input[found_set].iter().min()?;
+
+        Some(min + max)
+    }
+}
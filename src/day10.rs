@@ -0,0 +1,127 @@
+use std::num::ParseIntError;
+
+use crate::error::{self, Error};
+use crate::Solution;
+
+/// The maximum difference in joltage an adapter can take from its input to its output.
+pub const MAX_JOLTAGE_STEP: u64 = 3;
+
+#[derive(Debug, thiserror::Error)]
+pub enum AdapterParseError {
+    #[error("{0}")]
+    Int(#[from] ParseIntError),
+    #[error(
+        "adapter rated {0} jolts leaves no room for a device rated {step} jolts higher",
+        step = MAX_JOLTAGE_STEP
+    )]
+    NoRoomForDevice(u64),
+}
+
+/// Read the joltage rating of one adapter, which must leave room for the device above it.
+fn parse_rating(line: &str) -> Result<u64, AdapterParseError> {
+    let rating = line.trim().parse::<u64>()?;
+    match rating.checked_add(MAX_JOLTAGE_STEP) {
+        Some(_) => Ok(rating),
+        None => Err(AdapterParseError::NoRoomForDevice(rating)),
+    }
+}
+
+/// All adapters in the bag, chained from the charging outlet up to the built-in adapter of the
+/// device.
+pub struct AdapterChain {
+    /// The joltage ratings in ascending order, starting with the outlet at 0 jolts and ending with
+    /// the device.
+    joltages: Vec<u64>,
+}
+
+impl AdapterChain {
+    /// Create the chain from the joltage ratings of the adapters in the bag, in any order. Fails if
+    /// an adapter is rated so high that the rating of the device does not fit into a `u64`.
+    pub fn new(mut adapters: Vec<u64>) -> Result<Self, AdapterParseError> {
+        adapters.sort_unstable();
+
+        // The device is always rated three jolts higher than the highest rated adapter.
+        let highest = adapters.last().copied().unwrap_or(0);
+        let device = highest
+            .checked_add(MAX_JOLTAGE_STEP)
+            .ok_or(AdapterParseError::NoRoomForDevice(highest))?;
+
+        let mut joltages = Vec::with_capacity(adapters.len() + 2);
+        joltages.push(0);
+        joltages.append(&mut adapters);
+        joltages.push(device);
+
+        Ok(Self { joltages })
+    }
+
+    /// The joltage ratings of the outlet, every adapter and the device in ascending order.
+    pub fn joltages(&self) -> &[u64] {
+        &self.joltages
+    }
+
+    /// Count how many times each difference in joltage occurs when using every adapter. Index `i`
+    /// contains the number of times the difference is `i` jolts. Returns `None` if there is a gap
+    /// between two adapters no adapter can bridge.
+    pub fn differences(&self) -> Option<[usize; MAX_JOLTAGE_STEP as usize + 1]> {
+        let mut differences = [0; MAX_JOLTAGE_STEP as usize + 1];
+        for pair in self.joltages.windows(2) {
+            let difference = pair[1] - pair[0];
+            if difference > MAX_JOLTAGE_STEP {
+                return None;
+            }
+
+            differences[difference as usize] += 1;
+        }
+
+        Some(differences)
+    }
+
+    /// Count the distinct arrangements of adapters that connect the outlet to the device. Returns
+    /// `None` if the number of arrangements does not fit into a `u128`.
+    pub fn num_arrangements(&self) -> Option<u128> {
+        // The number of ways to reach every joltage from the outlet. Since the joltages are sorted,
+        // only the few joltages right before the current one can be connected to it, so every
+        // joltage only has to look back at most three entries, no matter how long the chain is.
+        let mut arrangements: Vec<u128> = Vec::with_capacity(self.joltages.len());
+        arrangements.push(1);
+
+        for i in 1..self.joltages.len() {
+            let mut ways: u128 = 0;
+            for j in (0..i).rev() {
+                if self.joltages[i] - self.joltages[j] > MAX_JOLTAGE_STEP {
+                    break;
+                }
+
+                ways = ways.checked_add(arrangements[j])?;
+            }
+
+            arrangements.push(ways);
+        }
+
+        arrangements.last().copied()
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = AdapterChain;
+    type AnswerA = usize;
+    type AnswerB = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        // Every rating has been checked to leave room for the device, so the chain can be built.
+        let ratings = error::parse_lines(input, parse_rating)?;
+        AdapterChain::new(ratings).map_err(|err| Error::parse(input, input, err))
+    }
+
+    // The number of one jolt differences multiplied by the number of three jolt differences
+    fn part_a(chain: &Self::Input) -> Option<Self::AnswerA> {
+        let differences = chain.differences()?;
+        Some(differences[1] * differences[3])
+    }
+
+    fn part_b(chain: &Self::Input) -> Option<Self::AnswerB> {
+        chain.num_arrangements()
+    }
+}
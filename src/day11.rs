@@ -0,0 +1,785 @@
+use std::fmt;
+use std::hash::Hash;
+use std::iter;
+use std::mem;
+use std::str::FromStr;
+#[cfg(feature = "parallel")]
+use std::thread;
+
+pub use crate::automaton::Settlement;
+
+use crate::automaton::{settle, Neighbourhood};
+use crate::error::Error;
+use crate::grid::{Grid, Topology};
+use crate::runner::Part;
+use crate::vector::Vec2;
+use crate::Solution;
+
+pub mod bitboard;
+pub mod render;
+pub mod report;
+
+/// The fewest rows worth handing to a thread of their own when stepping in parallel.
+#[cfg(feature = "parallel")]
+pub const MIN_ROWS_PER_THREAD: usize = 16;
+
+/// The number of generations after which the solution gives up if the seats keep changing.
+pub const MAX_GENERATIONS: usize = 10_000;
+
+/// The offsets to the eight tiles around a seat. `automaton::Moore` describes the same
+/// neighbourhood for sparse grids with any number of dimensions.
+pub const DIRECTIONS: [Vec2<isize>; 8] = [
+    Vec2::new(-1, -1),
+    Vec2::new(-1, 0),
+    Vec2::new(-1, 1),
+    Vec2::new(0, -1),
+    Vec2::new(0, 1),
+    Vec2::new(1, -1),
+    Vec2::new(1, 0),
+    Vec2::new(1, 1),
+];
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Tile {
+    Floor,
+    SeatEmpty,
+    SeatTaken,
+}
+
+/// A rectangular map of tiles. Unless another topology is chosen, the map ends at its edges.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TileMap {
+    grid: Grid<Tile>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TileMapParseError {
+    /// The character that is not a tile and its byte offset in the map string.
+    #[error("character '{0}' is not a tile")]
+    UnknownTile(char, usize),
+    /// The width of the first row, the width of the offending row and its byte offset in the map
+    /// string.
+    #[error("all rows must be {0} tiles wide, but this one has {1}")]
+    RowLength(usize, usize, usize),
+}
+
+impl Tile {
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Self::Floor),
+            'L' => Some(Self::SeatEmpty),
+            '#' => Some(Self::SeatTaken),
+            _ => None,
+        }
+    }
+
+    /// The character of the tile in the map text, the reverse of `from_char`.
+    pub fn to_char(self) -> char {
+        match self {
+            Self::Floor => '.',
+            Self::SeatEmpty => 'L',
+            Self::SeatTaken => '#',
+        }
+    }
+
+    pub fn occupied(&self) -> bool {
+        matches!(self, Self::SeatTaken)
+    }
+}
+
+/// Writes the character the tile is parsed from, padded to the width of the formatter.
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = [0; 4];
+        f.pad(self.to_char().encode_utf8(&mut buf))
+    }
+}
+
+impl TileMap {
+    pub fn with_size(size: (usize, usize)) -> Self {
+        Self {
+            grid: Grid::filled(size, Tile::Floor, Topology::Bounded),
+        }
+    }
+
+    pub fn topology(&self) -> Topology {
+        self.grid.topology()
+    }
+
+    /// Use the topology for the map from now on, so seats can see each other across the edges it
+    /// connects.
+    pub fn with_topology(mut self, topology: Topology) -> Self {
+        self.grid.set_topology(topology);
+        self
+    }
+
+    pub fn num_occupied(&self) -> usize {
+        self.grid
+            .cells()
+            .iter()
+            .filter(|tile| tile.occupied())
+            .count()
+    }
+
+    /// The tiles of the map, one row after the other.
+    pub fn tiles(&self) -> &[Tile] {
+        self.grid.cells()
+    }
+
+    pub fn num_adjacent_occupied(&self, pos: Vec2<usize>) -> u8 {
+        assert!(self.get(pos).is_some());
+
+        if self.topology() != Topology::Bounded {
+            return DIRECTIONS
+                .iter()
+                .filter_map(|&dir| self.grid.offset(pos, dir))
+                .filter(|&pos| self.get(pos).is_some_and(|tile| tile.occupied()))
+                .count() as u8;
+        }
+
+        // Only look at the rows and columns of the block around the position that are inside the
+        // map.
+        let (width, height) = self.size();
+        let first_x = pos.x.saturating_sub(1);
+        let last_x = (pos.x + 1).min(width - 1);
+        let mut count = 0;
+        for y in pos.y.saturating_sub(1)..=(pos.y + 1).min(height - 1) {
+            let row = &self.grid.cells()[y * width..(y + 1) * width];
+            for (x, tile) in (first_x..).zip(&row[first_x..=last_x]) {
+                if (x != pos.x || y != pos.y) && tile.occupied() {
+                    count += 1;
+                }
+            }
+        }
+
+        count
+    }
+
+    pub fn find_view(&self, pos: Vec2<usize>, dir: Vec2<isize>) -> Option<Tile> {
+        self.find_visible_seat(pos, dir)
+            .and_then(|pos| self.get(pos))
+    }
+
+    /// The position of the first seat that can be seen from the position when looking in the
+    /// direction, or `None` if there is only floor until the edge of the map. On maps that wrap,
+    /// looking stops when the view gets back to where it started.
+    pub fn find_visible_seat(&self, start: Vec2<usize>, dir: Vec2<isize>) -> Option<Vec2<usize>> {
+        let mut pos = start;
+        for _ in 0..self.grid.cells().len() {
+            pos = self.grid.offset(pos, dir)?;
+            if pos == start {
+                return None;
+            }
+
+            if self.get(pos)? != Tile::Floor {
+                return Some(pos);
+            }
+        }
+
+        None
+    }
+
+    pub fn num_los_occupied(&self, pos: Vec2<usize>) -> u8 {
+        let mut count = 0;
+        for dir in &DIRECTIONS {
+            if let Some(Tile::SeatTaken) = self.find_view(pos, *dir) {
+                count += 1;
+            }
+        }
+
+        count
+    }
+
+    /// The tile at the position, wrapped around the edges the topology connects.
+    pub fn get(&self, pos: Vec2<usize>) -> Option<Tile> {
+        self.grid.get(pos).copied()
+    }
+
+    pub fn set(&mut self, pos: Vec2<usize>, tile: Tile) {
+        *self.grid.get_mut(pos).expect("Tile does not exist") = tile;
+    }
+
+    pub fn size(&self) -> (usize, usize) {
+        self.grid.size()
+    }
+}
+
+/// A map of seats that can be stepped by the rules of both parts. `TileMap` and the bit-packed
+/// `bitboard::BitMap` both implement it, so code written against it runs on either.
+pub trait SeatMap: Clone + Eq + Hash {
+    fn size(&self) -> (usize, usize);
+
+    /// The tile at the position, or `None` if it is not on the map.
+    fn get(&self, pos: Vec2<usize>) -> Option<Tile>;
+
+    fn set(&mut self, pos: Vec2<usize>, tile: Tile);
+
+    fn num_occupied(&self) -> usize;
+
+    /// The number of occupied seats right next to the position, as the rule of part a sees them.
+    fn num_adjacent_occupied(&self, pos: Vec2<usize>) -> u8;
+
+    /// The number of occupied seats visible from the position, as the rule of part b sees them.
+    fn num_los_occupied(&self, pos: Vec2<usize>) -> u8;
+
+    /// Write the next generation of part a into the target, which must have the same size.
+    fn perform_step_a(&self, target: &mut Self);
+
+    /// Write the next generation of part b into the target, which must have the same size.
+    fn perform_step_b(&self, target: &mut Self);
+
+    /// Perform steps of the part until a generation repeats an earlier one, same as
+    /// `Seating::simulate`.
+    fn simulate(&self, part: Part, max_generations: usize) -> Settlement<Self> {
+        settle(self.clone(), max_generations, |map| {
+            let mut next = map.clone();
+            match part {
+                Part::A => map.perform_step_a(&mut next),
+                Part::B => map.perform_step_b(&mut next),
+            }
+            next
+        })
+    }
+}
+
+impl SeatMap for TileMap {
+    fn size(&self) -> (usize, usize) {
+        TileMap::size(self)
+    }
+
+    fn get(&self, pos: Vec2<usize>) -> Option<Tile> {
+        TileMap::get(self, pos)
+    }
+
+    fn set(&mut self, pos: Vec2<usize>, tile: Tile) {
+        TileMap::set(self, pos, tile)
+    }
+
+    fn num_occupied(&self) -> usize {
+        TileMap::num_occupied(self)
+    }
+
+    fn num_adjacent_occupied(&self, pos: Vec2<usize>) -> u8 {
+        TileMap::num_adjacent_occupied(self, pos)
+    }
+
+    fn num_los_occupied(&self, pos: Vec2<usize>) -> u8 {
+        TileMap::num_los_occupied(self, pos)
+    }
+
+    fn perform_step_a(&self, target: &mut Self) {
+        perform_step_a(self, target)
+    }
+
+    fn perform_step_b(&self, target: &mut Self) {
+        perform_step_b(self, target)
+    }
+}
+
+impl FromStr for TileMap {
+    type Err = TileMapParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut tiles = Vec::with_capacity(s.len());
+        let mut width = None;
+        let mut height = 0;
+        for line in s.lines() {
+            let line_offset = line.as_ptr() as usize - s.as_ptr() as usize;
+            let row_start = tiles.len();
+            for (i, c) in line.char_indices() {
+                match Tile::from_char(c) {
+                    Some(tile) => tiles.push(tile),
+                    None => return Err(Self::Err::UnknownTile(c, line_offset + i)),
+                }
+            }
+
+            // The map must be rectangular, or the steps would run off the shorter rows.
+            let row_len = tiles.len() - row_start;
+            match width {
+                Some(width) if width != row_len => {
+                    return Err(Self::Err::RowLength(width, row_len, line_offset))
+                }
+                _ => width = Some(row_len),
+            }
+
+            height += 1;
+        }
+
+        Ok(Self {
+            grid: Grid::from_cells(tiles, (width.unwrap_or(0), height), Topology::Bounded),
+        })
+    }
+}
+
+/// Writes the map in the same format it is parsed from, one line per row.
+impl fmt::Display for TileMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.grid.rows() {
+            for tile in row {
+                write!(f, "{}", tile)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The tile at the position in the next generation of part a.
+fn next_tile_a(source: &TileMap, pos: Vec2<usize>) -> Tile {
+    let tile = source.get(pos).expect("Tile does not exist");
+    RULE_A.next_tile(tile, source.num_adjacent_occupied(pos) as usize)
+}
+
+/// The tile at the position in the next generation of part b.
+fn next_tile_b(source: &TileMap, pos: Vec2<usize>) -> Tile {
+    let tile = source.get(pos).expect("Tile does not exist");
+    RULE_B.next_tile(tile, source.num_los_occupied(pos) as usize)
+}
+
+pub fn perform_step_a(source: &TileMap, target: &mut TileMap) {
+    step_rows(source, 0, target.grid.cells_mut(), &next_tile_a);
+}
+
+pub fn perform_step_b(source: &TileMap, target: &mut TileMap) {
+    step_rows(source, 0, target.grid.cells_mut(), &next_tile_b);
+}
+
+/// Compute the next generation of the rows starting at `first_row` into `rows`, which holds the
+/// tiles of whole rows one after the other.
+fn step_rows<F>(source: &TileMap, first_row: usize, rows: &mut [Tile], next_tile: &F)
+where
+    F: Fn(&TileMap, Vec2<usize>) -> Tile,
+{
+    let width = source.grid.width();
+    if width == 0 {
+        return;
+    }
+
+    for (y, row) in (first_row..).zip(rows.chunks_mut(width)) {
+        for (x, tile) in row.iter_mut().enumerate() {
+            *tile = next_tile(source, Vec2::new(x, y));
+        }
+    }
+}
+
+/// The number of threads worth stepping the map on: One per available core, but small maps are
+/// not worth the cost of starting threads and are stepped on the current one.
+#[cfg(feature = "parallel")]
+fn default_num_threads(source: &TileMap) -> usize {
+    let max_threads = thread::available_parallelism().map_or(1, |threads| threads.get());
+    (source.size().1 / MIN_ROWS_PER_THREAD).clamp(1, max_threads)
+}
+
+/// Split the map into `num_threads` bands of rows and compute the next generation of every band
+/// on its own thread. There are never more bands than rows.
+#[cfg(feature = "parallel")]
+fn perform_step_parallel<F>(
+    source: &TileMap,
+    target: &mut TileMap,
+    num_threads: usize,
+    next_tile: F,
+) where
+    F: Fn(&TileMap, Vec2<usize>) -> Tile + Sync,
+{
+    let (width, height) = source.size();
+    let num_threads = num_threads.clamp(1, height.max(1));
+    if num_threads == 1 || width == 0 {
+        step_rows(source, 0, target.grid.cells_mut(), &next_tile);
+        return;
+    }
+
+    let next_tile = &next_tile;
+    let band_height = height.div_ceil(num_threads);
+    thread::scope(|scope| {
+        for (band, rows) in target
+            .grid
+            .cells_mut()
+            .chunks_mut(band_height * width)
+            .enumerate()
+        {
+            scope.spawn(move || step_rows(source, band * band_height, rows, next_tile));
+        }
+    });
+}
+
+/// Same as `perform_step_a`, but with the rows split across multiple threads.
+#[cfg(feature = "parallel")]
+pub fn perform_step_a_parallel(source: &TileMap, target: &mut TileMap) {
+    perform_step_a_parallel_with(source, target, default_num_threads(source));
+}
+
+/// Same as `perform_step_a_parallel`, but with the rows split across `num_threads` threads no
+/// matter how large the map is.
+#[cfg(feature = "parallel")]
+pub fn perform_step_a_parallel_with(source: &TileMap, target: &mut TileMap, num_threads: usize) {
+    perform_step_parallel(source, target, num_threads, next_tile_a);
+}
+
+/// Same as `perform_step_b`, but with the rows split across multiple threads.
+#[cfg(feature = "parallel")]
+pub fn perform_step_b_parallel(source: &TileMap, target: &mut TileMap) {
+    perform_step_b_parallel_with(source, target, default_num_threads(source));
+}
+
+/// Same as `perform_step_b_parallel`, but with the rows split across `num_threads` threads no
+/// matter how large the map is.
+#[cfg(feature = "parallel")]
+pub fn perform_step_b_parallel_with(source: &TileMap, target: &mut TileMap, num_threads: usize) {
+    perform_step_parallel(source, target, num_threads, next_tile_b);
+}
+
+/// Which tiles count as the neighbours of a seat.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SeatNeighbourhood {
+    /// The eight tiles right next to the seat.
+    Adjacent,
+    /// The first seat in each of the eight directions, looking across the floor.
+    LineOfSight,
+    /// All tiles at most this many steps away, counting diagonal steps as one.
+    Radius(usize),
+}
+
+impl SeatNeighbourhood {
+    /// The positions of the neighbours of the position in the map. On small maps that wrap, the
+    /// same tile can be a neighbour more than once.
+    fn neighbours(self, map: &TileMap, pos: Vec2<usize>) -> Vec<Vec2<usize>> {
+        let radius = match self {
+            Self::Adjacent => 1,
+            Self::Radius(radius) => radius as isize,
+            Self::LineOfSight => {
+                return DIRECTIONS
+                    .iter()
+                    .filter_map(|&dir| map.find_visible_seat(pos, dir))
+                    .collect()
+            }
+        };
+
+        let mut neighbours = Vec::new();
+        for y in -radius..=radius {
+            for x in -radius..=radius {
+                if x != 0 || y != 0 {
+                    neighbours.extend(map.grid.offset(pos, Vec2::new(x, y)));
+                }
+            }
+        }
+
+        neighbours
+    }
+}
+
+/// The seats of one map as a space of cells for `automaton`, where every tile is a cell named by
+/// its index in the tiles of the map and its neighbours are the ones of the seat neighbourhood.
+#[derive(Copy, Clone, Debug)]
+pub struct SeatSpace<'a> {
+    map: &'a TileMap,
+    neighbourhood: SeatNeighbourhood,
+}
+
+impl<'a> SeatSpace<'a> {
+    pub fn new(map: &'a TileMap, neighbourhood: SeatNeighbourhood) -> Self {
+        Self { map, neighbourhood }
+    }
+}
+
+impl Neighbourhood for SeatSpace<'_> {
+    type Cell = usize;
+
+    fn neighbours(&self, i: usize) -> Vec<usize> {
+        let map = self.map;
+        self.neighbourhood
+            .neighbours(map, map.grid.position(i))
+            .into_iter()
+            .map(|neighbour| map.grid.index(neighbour).expect("Tile does not exist"))
+            .collect()
+    }
+}
+
+/// When seats are taken and when they are left, depending on the number of occupied neighbours.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SeatingRule {
+    pub neighbourhood: SeatNeighbourhood,
+    /// An empty seat is taken if at most this many neighbours are occupied.
+    pub occupy_threshold: usize,
+    /// An occupied seat is left if at least this many neighbours are occupied.
+    pub vacate_threshold: usize,
+}
+
+/// Seats are taken when no adjacent seat is and left when four or more are.
+pub const RULE_A: SeatingRule = SeatingRule {
+    neighbourhood: SeatNeighbourhood::Adjacent,
+    occupy_threshold: 0,
+    vacate_threshold: 4,
+};
+
+/// Seats are taken when no visible seat is and left when five or more are.
+pub const RULE_B: SeatingRule = SeatingRule {
+    neighbourhood: SeatNeighbourhood::LineOfSight,
+    occupy_threshold: 0,
+    vacate_threshold: 5,
+};
+
+impl SeatingRule {
+    /// The tile in the next generation, given the tile and how many of its neighbours are
+    /// occupied.
+    pub fn next_tile(&self, tile: Tile, num_occupied: usize) -> Tile {
+        match tile {
+            Tile::SeatEmpty if num_occupied <= self.occupy_threshold => Tile::SeatTaken,
+            Tile::SeatTaken if num_occupied >= self.vacate_threshold => Tile::SeatEmpty,
+            tile => tile,
+        }
+    }
+}
+
+/// A seating rule applied to one seat layout. The neighbours of every seat are found once, so
+/// that they do not have to be looked up again on every step. Since seats never turn into floor,
+/// this stays valid for every generation of the map it was created for.
+#[derive(Clone, Debug)]
+pub struct Seating {
+    rule: SeatingRule,
+    width: usize,
+    /// Where the neighbours of each tile start in `neighbours`, with one more entry at the end,
+    /// so the neighbours of tile `i` are `neighbours[starts[i]..starts[i + 1]]`.
+    starts: Vec<usize>,
+    /// The indices of the neighbours in the tiles of the map, for all tiles one after the other.
+    neighbours: Vec<usize>,
+}
+
+impl Seating {
+    pub fn new(map: &TileMap, rule: SeatingRule) -> Self {
+        let space = SeatSpace::new(map, rule.neighbourhood);
+        let mut starts = Vec::with_capacity(map.tiles().len() + 1);
+        let mut neighbours = Vec::new();
+        for (i, tile) in map.tiles().iter().enumerate() {
+            starts.push(neighbours.len());
+
+            // Floor never changes, so its neighbours do not matter.
+            if *tile == Tile::Floor {
+                continue;
+            }
+
+            neighbours.extend(space.neighbours(i));
+        }
+        starts.push(neighbours.len());
+
+        Self {
+            rule,
+            width: map.grid.width(),
+            starts,
+            neighbours,
+        }
+    }
+
+    pub fn rule(&self) -> &SeatingRule {
+        &self.rule
+    }
+
+    /// The indices of the neighbours of the tile with the index.
+    pub fn neighbours(&self, i: usize) -> &[usize] {
+        &self.neighbours[self.starts[i]..self.starts[i + 1]]
+    }
+
+    /// The number of occupied neighbours of the position.
+    pub fn num_occupied(&self, map: &TileMap, pos: Vec2<usize>) -> usize {
+        self.neighbours(pos.y * self.width + pos.x)
+            .iter()
+            .filter(|&&neighbour| map.tiles()[neighbour].occupied())
+            .count()
+    }
+
+    /// The tile at the position in the next generation.
+    pub fn next_tile(&self, source: &TileMap, pos: Vec2<usize>) -> Tile {
+        let tile = source.get(pos).expect("Tile does not exist");
+        self.rule.next_tile(tile, self.num_occupied(source, pos))
+    }
+
+    pub fn perform_step(&self, source: &TileMap, target: &mut TileMap) {
+        step_rows(
+            source,
+            0,
+            target.grid.cells_mut(),
+            &|source: &TileMap, pos| self.next_tile(source, pos),
+        );
+    }
+
+    /// Same as `perform_step`, but with the rows split across multiple threads.
+    #[cfg(feature = "parallel")]
+    pub fn perform_step_parallel(&self, source: &TileMap, target: &mut TileMap) {
+        self.perform_step_parallel_with(source, target, default_num_threads(source));
+    }
+
+    /// Same as `perform_step_parallel`, but with the rows split across `num_threads` threads no
+    /// matter how large the map is.
+    #[cfg(feature = "parallel")]
+    pub fn perform_step_parallel_with(
+        &self,
+        source: &TileMap,
+        target: &mut TileMap,
+        num_threads: usize,
+    ) {
+        perform_step_parallel(source, target, num_threads, |source: &TileMap, pos| {
+            self.next_tile(source, pos)
+        });
+    }
+
+    /// Perform steps on the map until it is stable, but only look at the seats that changed in
+    /// the previous generation or have a neighbour that did, since all others cannot change.
+    /// Returns `None` if the map is still changing after `max_generations` steps, which is the
+    /// case if the rule makes it oscillate. Use `simulate` to tell the two apart.
+    pub fn run_incremental(&self, map: &TileMap, max_generations: usize) -> Option<StableMap> {
+        let mut map = map.clone();
+        let mut active: Vec<usize> = (0..map.tiles().len())
+            .filter(|&i| map.tiles()[i] != Tile::Floor)
+            .collect();
+        let mut changes = Vec::new();
+        // The last generation each tile has been added to the active tiles for, so that it is
+        // only looked at once per generation.
+        let mut queued = vec![0; map.tiles().len()];
+        let mut generations = 0;
+
+        loop {
+            // Find all changes first and apply them afterwards, so every seat sees the previous
+            // generation.
+            changes.clear();
+            for &i in &active {
+                let next = self.next_tile(&map, map.grid.position(i));
+                if next != map.tiles()[i] {
+                    changes.push((i, next));
+                }
+            }
+
+            if changes.is_empty() {
+                break;
+            }
+            if generations == max_generations {
+                return None;
+            }
+
+            // Tiles are neighbours of each other both ways, so the tiles that can change next
+            // are the neighbours of the ones that just did.
+            generations += 1;
+            active.clear();
+            for &(i, tile) in &changes {
+                map.grid.cells_mut()[i] = tile;
+                for &j in iter::once(&i).chain(self.neighbours(i)) {
+                    if queued[j] != generations && map.tiles()[j] != Tile::Floor {
+                        queued[j] = generations;
+                        active.push(j);
+                    }
+                }
+            }
+        }
+
+        Some(StableMap { map, generations })
+    }
+
+    /// Every generation of the map, starting with the map itself and ending with the first one
+    /// the rule does not change anymore. Never ends if the rule makes the map oscillate.
+    pub fn generations(&self, map: &TileMap) -> Generations<'_> {
+        Generations {
+            seating: self,
+            next: Some(map.clone()),
+        }
+    }
+
+    /// Perform steps on the map until a generation repeats an earlier one, or until
+    /// `max_generations` steps have been performed without that happening.
+    pub fn simulate(&self, map: &TileMap, max_generations: usize) -> Settlement<TileMap> {
+        settle(map.clone(), max_generations, |map| {
+            let mut next = map.clone();
+            self.perform_step(map, &mut next);
+            next
+        })
+    }
+}
+
+/// Perform steps on the map until it has reached a stable state and return the stable map.
+/// Returns `None` if the map is still changing after `max_generations` steps, which is the case if
+/// the steps make it oscillate. Use `Seating::simulate` to tell the two apart.
+pub fn run_until_stable<F>(
+    map: &TileMap,
+    max_generations: usize,
+    mut perform_step: F,
+) -> Option<TileMap>
+where
+    F: FnMut(&TileMap, &mut TileMap),
+{
+    // The source and target maps are switched on each step.
+    let mut source = map.clone();
+    let mut target = map.clone();
+    let mut generations = 0;
+    loop {
+        perform_step(&source, &mut target);
+
+        // When we've reached a stable state, stop
+        if source == target {
+            return Some(source);
+        }
+        if generations == max_generations {
+            return None;
+        }
+
+        generations += 1;
+        mem::swap(&mut source, &mut target);
+    }
+}
+
+/// Iterator over the generations of a map, created by `Seating::generations`.
+pub struct Generations<'a> {
+    seating: &'a Seating,
+    next: Option<TileMap>,
+}
+
+impl Iterator for Generations<'_> {
+    type Item = TileMap;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next.take()?;
+        let mut next = current.clone();
+        self.seating.perform_step(&current, &mut next);
+        if next != current {
+            self.next = Some(next);
+        }
+
+        Some(current)
+    }
+}
+
+/// A map no step changes anymore, together with the number of steps that changed the map before
+/// it got there.
+#[derive(Clone, Debug)]
+pub struct StableMap {
+    pub map: TileMap,
+    pub generations: usize,
+}
+
+/// Same as running `run_until_stable` with `perform_step_a`, but only looking at seats that could
+/// change. Returns `None` if the map is still changing after `MAX_GENERATIONS` steps.
+pub fn run_incremental_a(map: &TileMap) -> Option<StableMap> {
+    Seating::new(map, RULE_A).run_incremental(map, MAX_GENERATIONS)
+}
+
+/// Same as running `run_until_stable` with `perform_step_b`, but only looking at seats that could
+/// change. Returns `None` if the map is still changing after `MAX_GENERATIONS` steps.
+pub fn run_incremental_b(map: &TileMap) -> Option<StableMap> {
+    Seating::new(map, RULE_B).run_incremental(map, MAX_GENERATIONS)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = TileMap;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        TileMap::from_str(input).map_err(|err| Error::parse(input, input, err))
+    }
+
+    fn part_a(map: &Self::Input) -> Option<Self::AnswerA> {
+        Some(run_incremental_a(map)?.map.num_occupied())
+    }
+
+    fn part_b(map: &Self::Input) -> Option<Self::AnswerB> {
+        Some(run_incremental_b(map)?.map.num_occupied())
+    }
+}
@@ -0,0 +1,355 @@
+//! Seat maps packed into bits, so a whole row of up to 64 tiles is stepped with a few word-wide
+//! operations instead of one tile at a time. Only the rule of part a can be computed like this,
+//! since looking across the floor for part b depends on the layout of every single row. `BitMap`
+//! still implements all of `SeatMap`, but its steps of part b look at one tile at a time and are
+//! no faster than those of `TileMap`.
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::mem;
+use std::str::FromStr;
+
+use super::{SeatMap, Tile, TileMap, TileMapParseError, DIRECTIONS, RULE_B};
+use crate::grid::Topology;
+use crate::vector::Vec2;
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A rectangular map of tiles, where every row is stored as a mask of the seats and a mask of the
+/// occupied seats. Bit `i` of word `w` in a row is the tile at `x = w * 64 + i`, and the bits past
+/// the end of a row are always zero.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct BitMap {
+    width: usize,
+    height: usize,
+    words_per_row: usize,
+    seats: Vec<u64>,
+    occupied: Vec<u64>,
+}
+
+impl BitMap {
+    pub fn with_size((width, height): (usize, usize)) -> Self {
+        let words_per_row = width.div_ceil(WORD_BITS);
+        Self {
+            width,
+            height,
+            words_per_row,
+            seats: vec![0; words_per_row * height],
+            occupied: vec![0; words_per_row * height],
+        }
+    }
+
+    pub fn num_occupied(&self) -> usize {
+        self.occupied
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// The word containing the position and the mask of the position in it.
+    fn bit(&self, pos: Vec2<usize>) -> (usize, u64) {
+        (
+            pos.y * self.words_per_row + pos.x / WORD_BITS,
+            1 << (pos.x % WORD_BITS),
+        )
+    }
+
+    pub fn get(&self, pos: Vec2<usize>) -> Option<Tile> {
+        if pos.x >= self.width || pos.y >= self.height {
+            return None;
+        }
+
+        let (word, mask) = self.bit(pos);
+        if self.occupied[word] & mask != 0 {
+            Some(Tile::SeatTaken)
+        } else if self.seats[word] & mask != 0 {
+            Some(Tile::SeatEmpty)
+        } else {
+            Some(Tile::Floor)
+        }
+    }
+
+    pub fn set(&mut self, pos: Vec2<usize>, tile: Tile) {
+        assert!(pos.x < self.width && pos.y < self.height);
+
+        let (word, mask) = self.bit(pos);
+        let (seat, occupied) = match tile {
+            Tile::Floor => (false, false),
+            Tile::SeatEmpty => (true, false),
+            Tile::SeatTaken => (true, true),
+        };
+
+        self.seats[word] = if seat {
+            self.seats[word] | mask
+        } else {
+            self.seats[word] & !mask
+        };
+        self.occupied[word] = if occupied {
+            self.occupied[word] | mask
+        } else {
+            self.occupied[word] & !mask
+        };
+    }
+
+    pub fn size(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    /// The position next to the position in the direction, or `None` if it is not on the map.
+    fn neighbour(&self, pos: Vec2<usize>, dir: Vec2<isize>) -> Option<Vec2<usize>> {
+        let x = pos.x.checked_add_signed(dir.x)?;
+        let y = pos.y.checked_add_signed(dir.y)?;
+        if x < self.width && y < self.height {
+            Some(Vec2::new(x, y))
+        } else {
+            None
+        }
+    }
+
+    pub fn num_adjacent_occupied(&self, pos: Vec2<usize>) -> u8 {
+        assert!(self.get(pos).is_some());
+
+        DIRECTIONS
+            .iter()
+            .filter_map(|&dir| self.neighbour(pos, dir))
+            .filter(|&pos| self.get(pos) == Some(Tile::SeatTaken))
+            .count() as u8
+    }
+
+    /// The first seat that can be seen from the position when looking in the direction, or `None`
+    /// if there is only floor until the edge of the map.
+    pub fn find_view(&self, pos: Vec2<usize>, dir: Vec2<isize>) -> Option<Tile> {
+        let mut pos = pos;
+        loop {
+            pos = self.neighbour(pos, dir)?;
+            match self.get(pos)? {
+                Tile::Floor => {}
+                seat => return Some(seat),
+            }
+        }
+    }
+
+    pub fn num_los_occupied(&self, pos: Vec2<usize>) -> u8 {
+        DIRECTIONS
+            .iter()
+            .filter(|&&dir| self.find_view(pos, dir) == Some(Tile::SeatTaken))
+            .count() as u8
+    }
+
+    /// The occupied mask of a row, or nothing if the row is outside the map.
+    fn occupied_row(&self, y: Option<usize>) -> &[u64] {
+        match y {
+            Some(y) if y < self.height => {
+                &self.occupied[y * self.words_per_row..(y + 1) * self.words_per_row]
+            }
+            _ => &[],
+        }
+    }
+}
+
+/// The error when converting a tile map that wraps around its edges into a bit map.
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+#[error("bit maps always end at their edges, but the map is {0:?}")]
+pub struct UnsupportedTopology(pub Topology);
+
+/// Bit maps always end at their edges, so only bounded tile maps can be converted. Maps with any
+/// other topology would step to different results.
+impl TryFrom<&TileMap> for BitMap {
+    type Error = UnsupportedTopology;
+
+    fn try_from(map: &TileMap) -> Result<Self, Self::Error> {
+        if map.topology() != Topology::Bounded {
+            return Err(UnsupportedTopology(map.topology()));
+        }
+
+        let mut bits = Self::with_size(map.size());
+        for (i, &tile) in map.tiles().iter().enumerate() {
+            bits.set(map.grid.position(i), tile);
+        }
+
+        Ok(bits)
+    }
+}
+
+impl From<&BitMap> for TileMap {
+    fn from(bits: &BitMap) -> Self {
+        let mut map = TileMap::with_size(bits.size());
+        for y in 0..bits.height {
+            for x in 0..bits.width {
+                let pos = Vec2::new(x, y);
+                map.set(pos, bits.get(pos).expect("Tile does not exist"));
+            }
+        }
+
+        map
+    }
+}
+
+impl FromStr for BitMap {
+    type Err = TileMapParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let map = s.parse::<TileMap>()?;
+        Ok(Self::try_from(&map).expect("Parsed maps are bounded"))
+    }
+}
+
+/// Writes the map in the same format it is parsed from, one line per row.
+impl fmt::Display for BitMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.height {
+            for x in 0..self.width {
+                write!(
+                    f,
+                    "{}",
+                    self.get(Vec2::new(x, y)).expect("Tile does not exist")
+                )?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Counts up how many neighbours are occupied for 64 tiles at once, one bit per tile. The count
+/// saturates at four, which is all the rule of part a needs to know.
+#[derive(Default)]
+struct Counter {
+    ones: u64,
+    twos: u64,
+    at_least_four: u64,
+}
+
+impl Counter {
+    fn add(&mut self, neighbours: u64) {
+        let carry_ones = self.ones & neighbours;
+        self.ones ^= neighbours;
+        let carry_twos = self.twos & carry_ones;
+        self.twos ^= carry_ones;
+        self.at_least_four |= carry_twos;
+    }
+
+    fn none(&self) -> u64 {
+        !(self.ones | self.twos | self.at_least_four)
+    }
+}
+
+/// Add the occupied seats of a row to the counters, shifted left and right for the tiles at
+/// `x - 1` and `x + 1`. The tile at `x` itself is only added if `center` is set.
+fn count_row(counters: &mut [Counter], row: &[u64], center: bool) {
+    for (w, counter) in counters.iter_mut().enumerate() {
+        let word = row.get(w).copied().unwrap_or(0);
+        let previous = if w > 0 {
+            row.get(w - 1).copied().unwrap_or(0)
+        } else {
+            0
+        };
+        let next = row.get(w + 1).copied().unwrap_or(0);
+
+        counter.add((word << 1) | (previous >> (WORD_BITS - 1)));
+        counter.add((word >> 1) | (next << (WORD_BITS - 1)));
+        if center {
+            counter.add(word);
+        }
+    }
+}
+
+/// Same as `day11::perform_step_a`, but for a whole word of tiles at once.
+pub fn perform_step_a(source: &BitMap, target: &mut BitMap) {
+    assert_eq!(source.size(), target.size());
+    target.seats.copy_from_slice(&source.seats);
+
+    let mut counters: Vec<Counter> = Vec::with_capacity(source.words_per_row);
+    for y in 0..source.height {
+        counters.clear();
+        counters.resize_with(source.words_per_row, Counter::default);
+
+        count_row(&mut counters, source.occupied_row(y.checked_sub(1)), true);
+        count_row(&mut counters, source.occupied_row(Some(y)), false);
+        count_row(&mut counters, source.occupied_row(Some(y + 1)), true);
+
+        let start = y * source.words_per_row;
+        for (w, counter) in counters.iter().enumerate() {
+            let seats = source.seats[start + w];
+            let occupied = source.occupied[start + w];
+
+            // Empty seats without occupied neighbours are taken, occupied seats with four or more
+            // are left.
+            target.occupied[start + w] =
+                seats & ((occupied & !counter.at_least_four) | (!occupied & counter.none()));
+        }
+    }
+}
+
+/// Same as `day11::perform_step_b`. Looking across the floor cannot be done for a word of tiles
+/// at once, so this goes through the tiles one by one.
+pub fn perform_step_b(source: &BitMap, target: &mut BitMap) {
+    assert_eq!(source.size(), target.size());
+
+    for y in 0..source.height {
+        for x in 0..source.width {
+            let pos = Vec2::new(x, y);
+            let tile = source.get(pos).expect("Tile does not exist");
+            target.set(
+                pos,
+                RULE_B.next_tile(tile, source.num_los_occupied(pos) as usize),
+            );
+        }
+    }
+}
+
+impl SeatMap for BitMap {
+    fn size(&self) -> (usize, usize) {
+        BitMap::size(self)
+    }
+
+    fn get(&self, pos: Vec2<usize>) -> Option<Tile> {
+        BitMap::get(self, pos)
+    }
+
+    fn set(&mut self, pos: Vec2<usize>, tile: Tile) {
+        BitMap::set(self, pos, tile)
+    }
+
+    fn num_occupied(&self) -> usize {
+        BitMap::num_occupied(self)
+    }
+
+    fn num_adjacent_occupied(&self, pos: Vec2<usize>) -> u8 {
+        BitMap::num_adjacent_occupied(self, pos)
+    }
+
+    fn num_los_occupied(&self, pos: Vec2<usize>) -> u8 {
+        BitMap::num_los_occupied(self, pos)
+    }
+
+    fn perform_step_a(&self, target: &mut Self) {
+        perform_step_a(self, target)
+    }
+
+    fn perform_step_b(&self, target: &mut Self) {
+        perform_step_b(self, target)
+    }
+}
+
+/// Perform steps of part a on the map until it has reached a stable state and return the stable
+/// map. Returns `None` if the map is still changing after `max_generations` steps, use
+/// `SeatMap::simulate` to tell oscillating maps apart.
+pub fn run_until_stable_a(map: &BitMap, max_generations: usize) -> Option<BitMap> {
+    let mut source = map.clone();
+    let mut target = BitMap::with_size(map.size());
+    let mut generations = 0;
+    loop {
+        perform_step_a(&source, &mut target);
+        if source == target {
+            return Some(source);
+        }
+        if generations == max_generations {
+            return None;
+        }
+
+        generations += 1;
+        mem::swap(&mut source, &mut target);
+    }
+}
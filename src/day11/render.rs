@@ -0,0 +1,95 @@
+//! Drawing the generations of a seat layout, to watch how a simulation unfolds.
+
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use super::{Tile, TileMap};
+
+/// Clears the terminal and moves the cursor to the top left corner, so every frame is drawn over
+/// the previous one.
+pub const ANSI_CLEAR: &str = "\x1b[2J\x1b[H";
+
+/// The ANSI escape sequence that colors a tile.
+fn ansi_color(tile: Tile) -> &'static str {
+    match tile {
+        Tile::Floor => "\x1b[90m",
+        Tile::SeatEmpty => "\x1b[32m",
+        Tile::SeatTaken => "\x1b[31m",
+    }
+}
+
+/// The color of a tile in images, as red, green and blue.
+fn rgb(tile: Tile) -> (u8, u8, u8) {
+    match tile {
+        Tile::Floor => (40, 40, 40),
+        Tile::SeatEmpty => (60, 180, 75),
+        Tile::SeatTaken => (230, 25, 75),
+    }
+}
+
+/// Render one generation for a terminal, in the same characters as the map text but with colors
+/// and a header line. The frame starts with `ANSI_CLEAR`.
+pub fn ansi_frame(map: &TileMap, generation: usize) -> String {
+    let (width, _) = map.size();
+    let mut frame = format!(
+        "{}generation {}, {} seats occupied\n",
+        ANSI_CLEAR,
+        generation,
+        map.num_occupied()
+    );
+
+    if width > 0 {
+        for row in map.tiles().chunks(width) {
+            for &tile in row {
+                frame.push_str(ansi_color(tile));
+                frame.push(tile.to_char());
+            }
+            frame.push_str("\x1b[0m\n");
+        }
+    }
+
+    frame
+}
+
+/// Render one generation as a plain (ASCII) PPM image, where every tile is a square of `scale`
+/// pixels.
+pub fn ppm_frame(map: &TileMap, scale: usize) -> String {
+    let (width, height) = map.size();
+    let mut image = format!("P3\n{} {}\n255\n", width * scale, height * scale);
+
+    if width > 0 {
+        for row in map.tiles().chunks(width) {
+            for _ in 0..scale {
+                for &tile in row {
+                    let (r, g, b) = rgb(tile);
+                    for _ in 0..scale {
+                        write!(image, "{} {} {} ", r, g, b).expect("Writing to a string failed");
+                    }
+                }
+                image.push('\n');
+            }
+        }
+    }
+
+    image
+}
+
+/// Write every generation as a PPM image into the directory, named by the number of the
+/// generation. Returns the number of images written.
+pub fn write_ppm_frames<I>(generations: I, dir: &Path, scale: usize) -> io::Result<usize>
+where
+    I: IntoIterator<Item = TileMap>,
+{
+    fs::create_dir_all(dir)?;
+
+    let mut count = 0;
+    for (generation, map) in generations.into_iter().enumerate() {
+        let path = dir.join(format!("generation_{:05}.ppm", generation));
+        fs::write(path, ppm_frame(&map, scale))?;
+        count += 1;
+    }
+
+    Ok(count)
+}
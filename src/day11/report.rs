@@ -0,0 +1,161 @@
+//! Statistics about how the seats of a map changed until they settled.
+
+use std::fmt;
+
+use super::{Seating, Tile, TileMap};
+
+/// What happened to every seat of a map while running a seating rule until the map was stable.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SeatReport {
+    width: usize,
+    generations: usize,
+    /// The number of occupied seats in every row of the stable map.
+    row_occupancy: Vec<usize>,
+    /// The number of occupied seats in every column of the stable map.
+    column_occupancy: Vec<usize>,
+    /// How often every tile changed, one row after the other.
+    flips: Vec<usize>,
+    /// The generation after which every tile did not change anymore, or `None` for floor.
+    settled: Vec<Option<usize>>,
+}
+
+impl SeatReport {
+    /// Run the seating rule on the map and record what happens to every seat. Returns `None` if
+    /// the map is not stable after `max_generations` steps.
+    pub fn new(seating: &Seating, map: &TileMap, max_generations: usize) -> Option<Self> {
+        let (width, height) = map.size();
+        let mut flips = vec![0; map.tiles().len()];
+        let mut settled: Vec<_> = map
+            .tiles()
+            .iter()
+            .map(|&tile| if tile == Tile::Floor { None } else { Some(0) })
+            .collect();
+
+        let mut generations = seating.generations(map);
+        let mut previous = generations.next()?;
+        let mut generation = 0;
+        for current in generations.by_ref().take(max_generations) {
+            generation += 1;
+            for (i, (before, after)) in previous.tiles().iter().zip(current.tiles()).enumerate() {
+                if before != after {
+                    flips[i] += 1;
+                    settled[i] = Some(generation);
+                }
+            }
+
+            previous = current;
+        }
+
+        // The iterator ends with the stable map, so if there are more maps left, it was not
+        // reached in time.
+        if generations.next().is_some() {
+            return None;
+        }
+
+        let mut row_occupancy = vec![0; height];
+        let mut column_occupancy = vec![0; width];
+        for (i, tile) in previous.tiles().iter().enumerate() {
+            if tile.occupied() {
+                row_occupancy[i / width] += 1;
+                column_occupancy[i % width] += 1;
+            }
+        }
+
+        Some(Self {
+            width,
+            generations: generation,
+            row_occupancy,
+            column_occupancy,
+            flips,
+            settled,
+        })
+    }
+
+    /// The number of steps that changed the map before it was stable.
+    pub fn generations(&self) -> usize {
+        self.generations
+    }
+
+    pub fn row_occupancy(&self) -> &[usize] {
+        &self.row_occupancy
+    }
+
+    pub fn column_occupancy(&self) -> &[usize] {
+        &self.column_occupancy
+    }
+
+    /// The number of seats that stayed as they were in the original map.
+    pub fn num_never_changed(&self) -> usize {
+        self.settled
+            .iter()
+            .filter(|&&settled| settled == Some(0))
+            .count()
+    }
+
+    /// The most times any seat changed, and how many seats changed that often.
+    pub fn most_flipped(&self) -> (usize, usize) {
+        let most = self.flips.iter().copied().max().unwrap_or(0);
+        let seats = self
+            .flips
+            .iter()
+            .zip(&self.settled)
+            .filter(|&(&flips, settled)| flips == most && settled.is_some())
+            .count();
+
+        (most, seats)
+    }
+
+    /// The generation after which every tile did not change anymore, one row after the other, or
+    /// `None` for floor.
+    pub fn settled(&self) -> &[Option<usize>] {
+        &self.settled
+    }
+}
+
+/// Prints a summary, followed by a table with the generation every seat settled in. The occupied
+/// seats of every row are written at the end of the row and those of every column below the
+/// column.
+impl fmt::Display for SeatReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (most_flips, most_flipped) = self.most_flipped();
+        writeln!(f, "stable after {} generations", self.generations)?;
+        writeln!(f, "seats never changed: {}", self.num_never_changed())?;
+        writeln!(
+            f,
+            "most changes of a seat: {} ({} seats)",
+            most_flips, most_flipped
+        )?;
+
+        if self.width == 0 {
+            return Ok(());
+        }
+
+        // All cells of the table are as wide as the widest number in it.
+        let widest = self
+            .column_occupancy
+            .iter()
+            .copied()
+            .chain(Some(self.generations))
+            .max()
+            .unwrap_or(0);
+        let cell_width = widest.to_string().len();
+
+        writeln!(f)?;
+        for (row, occupied) in self.settled.chunks(self.width).zip(&self.row_occupancy) {
+            for settled in row {
+                match settled {
+                    Some(generation) => write!(f, "{:>1$} ", generation, cell_width)?,
+                    None => write!(f, "{:>1$} ", Tile::Floor, cell_width)?,
+                }
+            }
+            writeln!(f, "| {}", occupied)?;
+        }
+
+        writeln!(f, "{}", "-".repeat((cell_width + 1) * self.width))?;
+        for (i, occupied) in self.column_occupancy.iter().enumerate() {
+            let separator = if i == 0 { "" } else { " " };
+            write!(f, "{}{:>2$}", separator, occupied, cell_width)?;
+        }
+        writeln!(f)
+    }
+}
@@ -0,0 +1,558 @@
+use std::convert::TryFrom;
+use std::fmt::{self, Write};
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+use crate::error::{self, Error};
+use crate::vector::Vec2;
+use crate::Solution;
+
+pub mod optimizer;
+pub mod planner;
+
+/// The number of degrees in one quarter turn, the only angle the ship can turn by.
+pub const QUARTER_TURN_DEG: u16 = 90;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CourseCommand {
+    DeltaLatitude(i32),
+    DeltaLongitude(i32),
+    /// Turn by a number of quarter turns. Positive values turn counter-clockwise.
+    Turn(i32),
+    Forward(u16),
+}
+
+/// One of the four cardinal directions the ship can be heading in.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Heading {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Heading {
+    /// All headings, in clockwise order starting with north.
+    pub const CLOCKWISE: [Heading; 4] =
+        [Heading::North, Heading::East, Heading::South, Heading::West];
+
+    /// The heading after turning a number of quarter turns. Positive values turn
+    /// counter-clockwise.
+    pub fn turned(self, quarter_turns: i32) -> Self {
+        let index = Self::CLOCKWISE
+            .iter()
+            .position(|&heading| heading == self)
+            .unwrap() as i32;
+
+        Self::CLOCKWISE[(index - quarter_turns.rem_euclid(4)).rem_euclid(4) as usize]
+    }
+
+    /// The change in (latitude, longitude) when moving one unit in this direction.
+    pub fn delta(self) -> Vec2<i32> {
+        match self {
+            Self::North => Vec2::new(-1, 0),
+            Self::East => Vec2::new(0, -1),
+            Self::South => Vec2::new(1, 0),
+            Self::West => Vec2::new(0, 1),
+        }
+    }
+}
+
+impl fmt::Display for Heading {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::North => write!(f, "N"),
+            Self::East => write!(f, "E"),
+            Self::South => write!(f, "S"),
+            Self::West => write!(f, "W"),
+        }
+    }
+}
+
+/// A waypoint that moves along with the ship. Its position is relative to the ship.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Waypoint {
+    /// The offset from the ship as (latitude, longitude).
+    pos: Vec2<i32>,
+}
+
+impl Waypoint {
+    pub fn pos(&self) -> Vec2<i32> {
+        self.pos
+    }
+
+    /// Rotate the waypoint around the ship by a number of quarter turns. Positive values rotate it
+    /// counter-clockwise, just like the ship turns when it is given the same value.
+    pub fn rotate(&mut self, quarter_turns: i32) {
+        // Latitude grows towards the south and longitude towards the west, so counter-clockwise
+        // on the compass is the opposite rotation of the vector.
+        self.pos = self.pos.rotated(-quarter_turns.rem_euclid(4));
+    }
+}
+
+impl Default for Waypoint {
+    /// The waypoint starts ten units east and one unit north of the ship.
+    fn default() -> Self {
+        Self {
+            pos: Vec2::new(-1, -10),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Ship {
+    /// The position as (latitude, longitude).
+    pos: Vec2<i32>,
+    heading: Heading,
+    waypoint: Waypoint,
+}
+
+impl Ship {
+    pub fn pos(&self) -> Vec2<i32> {
+        self.pos
+    }
+
+    pub fn heading(&self) -> Heading {
+        self.heading
+    }
+
+    pub fn waypoint(&self) -> &Waypoint {
+        &self.waypoint
+    }
+
+    /// Follow a command, moving or turning the ship.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ship would move outside the range of an `i32`. Use `checked_set_course` for
+    /// courses that might.
+    pub fn set_course(&mut self, command: CourseCommand) {
+        self.checked_set_course(command)
+            .expect("course moves the ship out of range")
+    }
+
+    /// Like `set_course`, but returns `None` and leaves the ship where it is if it would move
+    /// outside the range of an `i32`.
+    pub fn checked_set_course(&mut self, command: CourseCommand) -> Option<()> {
+        let delta = match command {
+            CourseCommand::DeltaLatitude(dlat) => Vec2::new(dlat, 0),
+            CourseCommand::DeltaLongitude(dlong) => Vec2::new(0, dlong),
+            CourseCommand::Turn(quarter_turns) => {
+                self.heading = self.heading.turned(quarter_turns);
+                return Some(());
+            }
+            CourseCommand::Forward(amount) => self.heading.delta() * amount as i32,
+        };
+
+        self.pos = self.pos.checked_add(delta)?;
+        Some(())
+    }
+
+    /// Follow a command by interpreting it relative to the waypoint. Moving and turning is
+    /// applied to the waypoint instead of the ship and going forward moves the ship towards the
+    /// waypoint as many times as the command says. The heading of the ship is unaffected.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ship or the waypoint would move outside the range of an `i32`. Use
+    /// `checked_set_course_by_waypoint` for courses that might.
+    pub fn set_course_by_waypoint(&mut self, command: CourseCommand) {
+        self.checked_set_course_by_waypoint(command)
+            .expect("course moves the ship out of range")
+    }
+
+    /// Like `set_course_by_waypoint`, but returns `None` and leaves the ship where it is if the
+    /// ship or the waypoint would move outside the range of an `i32`.
+    pub fn checked_set_course_by_waypoint(&mut self, command: CourseCommand) -> Option<()> {
+        let waypoint = self.waypoint.pos;
+        match command {
+            CourseCommand::DeltaLatitude(dlat) => {
+                self.waypoint.pos = waypoint.checked_add(Vec2::new(dlat, 0))?
+            }
+            CourseCommand::DeltaLongitude(dlong) => {
+                self.waypoint.pos = waypoint.checked_add(Vec2::new(0, dlong))?
+            }
+            CourseCommand::Turn(quarter_turns) => self.waypoint.rotate(quarter_turns),
+            CourseCommand::Forward(amount) => {
+                self.pos = self.pos.checked_add(waypoint.checked_mul(amount as i32)?)?
+            }
+        }
+
+        Some(())
+    }
+
+    /// Follow every command of the course and record where the ship is after each of them.
+    pub fn replay(&mut self, commands: &[CourseCommand]) -> Track {
+        self.replay_with(commands, Self::set_course)
+    }
+
+    /// Like `replay`, but the commands are interpreted relative to the waypoint.
+    pub fn replay_by_waypoint(&mut self, commands: &[CourseCommand]) -> Track {
+        self.replay_with(commands, Self::set_course_by_waypoint)
+    }
+
+    fn replay_with(
+        &mut self,
+        commands: &[CourseCommand],
+        set_course: fn(&mut Self, CourseCommand),
+    ) -> Track {
+        let mut points = Vec::with_capacity(commands.len() + 1);
+        points.push(self.track_point());
+        for &command in commands {
+            set_course(self, command);
+            points.push(self.track_point());
+        }
+
+        Track { points }
+    }
+
+    fn track_point(&self) -> TrackPoint {
+        TrackPoint {
+            pos: self.pos,
+            heading: self.heading,
+        }
+    }
+}
+
+impl Default for Ship {
+    fn default() -> Self {
+        Self {
+            pos: Vec2::new(0, 0),
+            heading: Heading::East,
+            waypoint: Waypoint::default(),
+        }
+    }
+}
+
+/// The state of the ship at one point of its course.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TrackPoint {
+    /// The position as (latitude, longitude).
+    pub pos: Vec2<i32>,
+    pub heading: Heading,
+}
+
+/// The course a ship has taken. The first point is where the ship started, every following point is
+/// the state of the ship after one more command.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Track {
+    points: Vec<TrackPoint>,
+}
+
+impl Track {
+    pub fn points(&self) -> &[TrackPoint] {
+        &self.points
+    }
+
+    /// Export the track as CSV, with one row per point and a header row.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("step,latitude,longitude,heading\n");
+        for (step, point) in self.points.iter().enumerate() {
+            writeln!(
+                csv,
+                "{},{},{},{}",
+                step, point.pos.x, point.pos.y, point.heading
+            )
+            .unwrap();
+        }
+
+        csv
+    }
+
+    /// Export the track as a GeoJSON feature containing a LineString. Since latitude grows
+    /// towards the south and longitude towards the west, both are negated so the route is not
+    /// mirrored in tools expecting east and north to be positive.
+    pub fn to_geojson(&self) -> String {
+        let coordinates: Vec<String> = self
+            .points
+            .iter()
+            .map(|point| format!("[{},{}]", -point.pos.y, -point.pos.x))
+            .collect();
+
+        format!(
+            "{{\"type\":\"Feature\",\"properties\":{{}},\"geometry\":{{\"type\":\"LineString\",\"coordinates\":[{}]}}}}",
+            coordinates.join(",")
+        )
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CourseCommandParseError {
+    #[error("course command is empty")]
+    Empty,
+    #[error("{0} is not a valid course word")]
+    UnknownWord(String),
+    #[error("unable to read value for command: {0}")]
+    Value(#[from] ParseIntError),
+    #[error(
+        "cannot turn by {0} degrees, only multiples of {deg} are allowed",
+        deg = QUARTER_TURN_DEG
+    )]
+    UnalignedTurn(u64),
+    #[error("value {0} is too large for this command")]
+    OutOfRange(u64),
+}
+
+/// Read the value of a command, negating it for the words pointing in the negative direction.
+fn signed_value(value: u64, negative: bool) -> Result<i32, CourseCommandParseError> {
+    let signed = if negative {
+        -(value as i128)
+    } else {
+        value as i128
+    };
+
+    i32::try_from(signed).map_err(|_| CourseCommandParseError::OutOfRange(value))
+}
+
+impl FromStr for CourseCommand {
+    type Err = CourseCommandParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let word_len = match s.chars().next() {
+            Some(c) => c.len_utf8(),
+            None => return Err(Self::Err::Empty),
+        };
+        let (word, attribute) = s.split_at(word_len);
+        // Values are read with more room than any command needs, so everything a command can hold
+        // can be read back from its text.
+        let attribute = attribute.parse::<u64>()?;
+
+        // Turns are only possible in full quarter turns.
+        let quarter_turns = |negative: bool| {
+            if attribute % QUARTER_TURN_DEG as u64 == 0 {
+                signed_value(attribute / QUARTER_TURN_DEG as u64, negative)
+                    .map_err(|_| Self::Err::OutOfRange(attribute))
+            } else {
+                Err(Self::Err::UnalignedTurn(attribute))
+            }
+        };
+
+        match word {
+            "N" => Ok(Self::DeltaLatitude(signed_value(attribute, true)?)),
+            "S" => Ok(Self::DeltaLatitude(signed_value(attribute, false)?)),
+            "E" => Ok(Self::DeltaLongitude(signed_value(attribute, true)?)),
+            "W" => Ok(Self::DeltaLongitude(signed_value(attribute, false)?)),
+            "L" => Ok(Self::Turn(quarter_turns(false)?)),
+            "R" => Ok(Self::Turn(quarter_turns(true)?)),
+            "F" => match u16::try_from(attribute) {
+                Ok(amount) => Ok(Self::Forward(amount)),
+                Err(_) => Err(Self::Err::OutOfRange(attribute)),
+            },
+            o => Err(Self::Err::UnknownWord(o.to_owned())),
+        }
+    }
+}
+
+/// Writes the command in the format it is read from, so reading the text again results in the
+/// same command. Turns are written in the direction they are going, so `Turn(-1)` becomes `R90`.
+impl fmt::Display for CourseCommand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Widen before negating and multiplying, so even the extreme values can be written.
+        let degrees = |quarter_turns: i32| quarter_turns as i64 * QUARTER_TURN_DEG as i64;
+        match *self {
+            Self::DeltaLatitude(delta) if delta < 0 => write!(f, "N{}", -(delta as i64)),
+            Self::DeltaLatitude(delta) => write!(f, "S{}", delta),
+            Self::DeltaLongitude(delta) if delta < 0 => write!(f, "E{}", -(delta as i64)),
+            Self::DeltaLongitude(delta) => write!(f, "W{}", delta),
+            Self::Turn(quarter_turns) if quarter_turns < 0 => {
+                write!(f, "R{}", -degrees(quarter_turns))
+            }
+            Self::Turn(quarter_turns) => write!(f, "L{}", degrees(quarter_turns)),
+            Self::Forward(amount) => write!(f, "F{}", amount),
+        }
+    }
+}
+
+impl CourseCommand {
+    /// The command with turns reduced to the shortest equivalent turn, preferring left turns for
+    /// half turns. The ship ends up in the same state either way, so `L270` becomes `R90` and
+    /// `R180` becomes `L180`. Other commands are returned as they are.
+    pub fn normalized(self) -> Self {
+        match self {
+            Self::Turn(quarter_turns) => match quarter_turns.rem_euclid(4) {
+                3 => Self::Turn(-1),
+                quarter_turns => Self::Turn(quarter_turns),
+            },
+            other => other,
+        }
+    }
+}
+
+/// Write a course in the text format the puzzle input uses, one command per line.
+pub fn format_course(commands: &[CourseCommand]) -> String {
+    let lines: Vec<String> = commands.iter().map(|command| command.to_string()).collect();
+    lines.join("\n")
+}
+
+/// A command for the continuous navigation mode, where the ship can turn by any angle instead of
+/// only quarter turns.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ContinuousCommand {
+    /// A command that works the same way as in the cardinal navigation mode.
+    Course(CourseCommand),
+    /// Turn by a number of degrees. Positive values turn counter-clockwise.
+    Turn(i32),
+}
+
+impl FromStr for ContinuousCommand {
+    type Err = CourseCommandParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Only turns are read differently, everything else is a normal course command.
+        if let Some(attribute) = s.strip_prefix('L') {
+            Ok(Self::Turn(signed_value(attribute.parse()?, false)?))
+        } else if let Some(attribute) = s.strip_prefix('R') {
+            Ok(Self::Turn(signed_value(attribute.parse()?, true)?))
+        } else {
+            Ok(Self::Course(CourseCommand::from_str(s)?))
+        }
+    }
+}
+
+impl fmt::Display for ContinuousCommand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Course(command) => write!(f, "{}", command),
+            Self::Turn(deg) if deg < 0 => write!(f, "R{}", -(deg as i64)),
+            Self::Turn(deg) => write!(f, "L{}", deg),
+        }
+    }
+}
+
+/// A ship that can head in any direction. Its position is tracked in full precision and is only
+/// rounded to whole units when it is reported with `pos`.
+#[derive(Clone, Debug)]
+pub struct ContinuousShip {
+    /// The position as (latitude, longitude).
+    pos: Vec2<f64>,
+    /// The heading in degrees counter-clockwise from north, always in the range `0..360`.
+    heading_deg: i32,
+}
+
+impl ContinuousShip {
+    /// The position of the ship, rounded to the nearest whole units.
+    pub fn pos(&self) -> Vec2<i32> {
+        Vec2::new(self.pos.x.round() as i32, self.pos.y.round() as i32)
+    }
+
+    /// The position of the ship without rounding.
+    pub fn exact_pos(&self) -> Vec2<f64> {
+        self.pos
+    }
+
+    /// The heading of the ship in degrees counter-clockwise from north.
+    pub fn heading_deg(&self) -> i32 {
+        self.heading_deg
+    }
+
+    /// The change in (latitude, longitude) when moving one unit in the direction of the heading.
+    /// Cardinal headings are exact, so courses without any uneven turns stay on whole units.
+    fn delta(&self) -> Vec2<f64> {
+        match self.heading_deg {
+            0 => Vec2::new(-1., 0.),
+            90 => Vec2::new(0., 1.),
+            180 => Vec2::new(1., 0.),
+            270 => Vec2::new(0., -1.),
+            deg => {
+                let (sin, cos) = (deg as f64).to_radians().sin_cos();
+                Vec2::new(-cos, sin)
+            }
+        }
+    }
+
+    pub fn set_course(&mut self, command: ContinuousCommand) {
+        match command {
+            ContinuousCommand::Turn(deg) => {
+                self.heading_deg = (self.heading_deg + deg.rem_euclid(360)).rem_euclid(360)
+            }
+            ContinuousCommand::Course(CourseCommand::Turn(quarter_turns)) => self.set_course(
+                ContinuousCommand::Turn(quarter_turns.rem_euclid(4) * QUARTER_TURN_DEG as i32),
+            ),
+            ContinuousCommand::Course(CourseCommand::DeltaLatitude(dlat)) => {
+                self.pos.x += dlat as f64
+            }
+            ContinuousCommand::Course(CourseCommand::DeltaLongitude(dlong)) => {
+                self.pos.y += dlong as f64
+            }
+            ContinuousCommand::Course(CourseCommand::Forward(amount)) => {
+                self.pos += self.delta() * amount as f64
+            }
+        }
+    }
+}
+
+/// Read a course for the continuous navigation mode, one command per line.
+pub fn parse_continuous(input: &str) -> Result<Vec<ContinuousCommand>, Error> {
+    error::parse_lines(input, ContinuousCommand::from_str)
+}
+
+/// The manhattan distance from the start to the end of the course when navigating continuously,
+/// rounded to whole units.
+pub fn continuous_distance(commands: &[ContinuousCommand]) -> i32 {
+    let mut ship = ContinuousShip::default();
+    let start_pos = ship.pos();
+    for &command in commands {
+        ship.set_course(command);
+    }
+
+    ship.pos().manhattan(start_pos)
+}
+
+impl Default for ContinuousShip {
+    fn default() -> Self {
+        Self {
+            pos: Vec2::new(0., 0.),
+            heading_deg: 270,
+        }
+    }
+}
+
+impl From<&Ship> for ContinuousShip {
+    /// Continue navigating continuously from where the ship currently is.
+    fn from(ship: &Ship) -> Self {
+        let pos = ship.pos();
+        let heading_deg = match ship.heading() {
+            Heading::North => 0,
+            Heading::West => 90,
+            Heading::South => 180,
+            Heading::East => 270,
+        };
+
+        Self {
+            pos: Vec2::new(pos.x as f64, pos.y as f64),
+            heading_deg,
+        }
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<CourseCommand>;
+    type AnswerA = i32;
+    type AnswerB = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        error::parse_lines(input, CourseCommand::from_str)
+    }
+
+    // The manhattan distance from the start to the end of the course is the answer for a)
+    fn part_a(commands: &Self::Input) -> Option<Self::AnswerA> {
+        let mut ship = Ship::default();
+        let start_pos = ship.pos();
+        for &command in commands {
+            ship.checked_set_course(command)?;
+        }
+
+        ship.pos().checked_manhattan(start_pos)
+    }
+
+    // Same as a), but the commands are interpreted relative to the waypoint
+    fn part_b(commands: &Self::Input) -> Option<Self::AnswerB> {
+        let mut ship = Ship::default();
+        let start_pos = ship.pos();
+        for &command in commands {
+            ship.checked_set_course_by_waypoint(command)?;
+        }
+
+        ship.pos().checked_manhattan(start_pos)
+    }
+}
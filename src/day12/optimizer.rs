@@ -0,0 +1,107 @@
+//! Shortening courses without changing where the ship ends up.
+//!
+//! Moving the ship north, south, east or west and turning it do not depend on each other, so all
+//! of these commands between two forward commands can be merged into at most one move along each
+//! axis and one turn. If the heading of the ship at the start of the course is known, forward
+//! commands can be turned into plain moves as well, which folds all turns into the final heading.
+//!
+//! The optimized course is only equivalent when following it with `Ship::set_course`, since
+//! navigating by waypoint interprets the commands differently.
+
+use std::convert::TryFrom;
+
+use super::{CourseCommand, Heading};
+
+/// The commands collected since the last forward command that has been kept.
+#[derive(Default)]
+struct Run {
+    delta_latitude: i64,
+    delta_longitude: i64,
+    quarter_turns: i64,
+}
+
+impl Run {
+    fn is_empty(&self) -> bool {
+        self.delta_latitude == 0 && self.delta_longitude == 0 && self.quarter_turns % 4 == 0
+    }
+
+    /// Write the merged commands of the run to the course and start a new run.
+    fn flush(&mut self, course: &mut Vec<CourseCommand>) {
+        push_delta(course, self.delta_latitude, CourseCommand::DeltaLatitude);
+        push_delta(course, self.delta_longitude, CourseCommand::DeltaLongitude);
+
+        let turn = CourseCommand::Turn(self.quarter_turns.rem_euclid(4) as i32).normalized();
+        if turn != CourseCommand::Turn(0) {
+            course.push(turn);
+        }
+
+        *self = Self::default();
+    }
+}
+
+/// Add a move to the course, split into multiple commands if it does not fit into one.
+fn push_delta(course: &mut Vec<CourseCommand>, mut delta: i64, command: fn(i32) -> CourseCommand) {
+    while delta != 0 {
+        let step = delta.clamp(i32::MIN as i64 + 1, i32::MAX as i64);
+        course.push(command(step as i32));
+        delta -= step;
+    }
+}
+
+/// Add a forward command to the course, merging it into the previous command if that one also
+/// goes forward.
+fn push_forward(course: &mut Vec<CourseCommand>, amount: u16) {
+    if let Some(CourseCommand::Forward(previous)) = course.last_mut() {
+        let total = *previous as u32 + amount as u32;
+        let merged = u16::try_from(total).unwrap_or(u16::MAX);
+        let rest = total - merged as u32;
+
+        *previous = merged;
+        if rest != 0 {
+            course.push(CourseCommand::Forward(rest as u16));
+        }
+    } else if amount != 0 {
+        course.push(CourseCommand::Forward(amount));
+    }
+}
+
+/// Shorten the course, so that a ship following it ends up at the same position with the same
+/// heading as when following the original course.
+///
+/// If the heading the ship starts with is known, forward commands are replaced by moves in that
+/// direction and the whole course shrinks to at most one move along each axis and one turn.
+/// Otherwise only the commands between forward commands are merged, as well as forward commands
+/// that directly follow each other.
+pub fn optimize_course(commands: &[CourseCommand], heading: Option<Heading>) -> Vec<CourseCommand> {
+    let mut course = Vec::new();
+    let mut run = Run::default();
+    let mut heading = heading;
+
+    for &command in commands {
+        match command {
+            CourseCommand::DeltaLatitude(delta) => run.delta_latitude += delta as i64,
+            CourseCommand::DeltaLongitude(delta) => run.delta_longitude += delta as i64,
+            CourseCommand::Turn(quarter_turns) => {
+                run.quarter_turns += quarter_turns as i64;
+                heading = heading.map(|heading| heading.turned(quarter_turns));
+            }
+            CourseCommand::Forward(amount) => match heading {
+                // The direction is known, so going forward is just another move.
+                Some(heading) => {
+                    let delta = heading.delta();
+                    run.delta_latitude += delta.x as i64 * amount as i64;
+                    run.delta_longitude += delta.y as i64 * amount as i64;
+                }
+                None => {
+                    if !run.is_empty() {
+                        run.flush(&mut course);
+                    }
+                    push_forward(&mut course, amount);
+                }
+            },
+        }
+    }
+
+    run.flush(&mut course);
+    course
+}
@@ -0,0 +1,131 @@
+//! Planning courses backwards: Finding the shortest list of commands that brings a ship to a
+//! target position.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+use super::{CourseCommand, Heading, Ship};
+use crate::vector::Vec2;
+
+/// A state of the ship that has been reached while searching, together with how it was reached.
+struct Node {
+    ship: Ship,
+    /// The number of commands needed to reach this state.
+    len: usize,
+    /// The node this one was reached from and the command that was used to get here.
+    parent: Option<(usize, CourseCommand)>,
+}
+
+/// The furthest a single command can move the ship, measured in manhattan distance. Distances are
+/// widened to `i64`, since the largest commands move further than an `i32` can hold.
+fn max_move(alphabet: &[CourseCommand]) -> i64 {
+    alphabet
+        .iter()
+        .map(|command| match *command {
+            CourseCommand::DeltaLatitude(delta) | CourseCommand::DeltaLongitude(delta) => {
+                (delta as i64).abs()
+            }
+            CourseCommand::Forward(amount) => amount as i64,
+            CourseCommand::Turn(_) => 0,
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// Find a course of minimal length that brings the ship from its current position to the target,
+/// using only commands from the alphabet. Commands can be used as often as needed. Only courses
+/// up to `max_len` commands are considered, so the search ends even if the target cannot be
+/// reached. Returns `None` if no such course exists.
+pub fn plan_course(
+    start: &Ship,
+    target: Vec2<i32>,
+    alphabet: &[CourseCommand],
+    max_len: usize,
+) -> Option<Vec<CourseCommand>> {
+    let max_move = max_move(alphabet);
+
+    // Lower bound of the number of commands still needed to reach the target. Every command can
+    // bring the ship at most `max_move` closer, so this never overestimates and the first course
+    // found is a shortest one.
+    let estimate = |ship: &Ship| -> usize {
+        let pos = ship.pos();
+        let distance =
+            (pos.x as i64 - target.x as i64).abs() + (pos.y as i64 - target.y as i64).abs();
+        if distance == 0 {
+            0
+        } else if max_move == 0 {
+            usize::MAX
+        } else {
+            ((distance + max_move - 1) / max_move) as usize
+        }
+    };
+
+    let mut nodes = vec![Node {
+        ship: start.clone(),
+        len: 0,
+        parent: None,
+    }];
+    // The shortest known number of commands to reach each state of the ship.
+    let mut best: HashMap<(Vec2<i32>, Heading), usize> = HashMap::new();
+    best.insert((start.pos(), start.heading()), 0);
+
+    // Nodes to be visited ordered by the estimated length of the full course through them. Ties
+    // are broken in favour of the node added first, so the search is deterministic.
+    let mut open = BinaryHeap::new();
+    open.push(Reverse((estimate(start), 0)));
+
+    while let Some(Reverse((_, index))) = open.pop() {
+        let node = &nodes[index];
+        if node.ship.pos() == target {
+            return Some(reconstruct(&nodes, index));
+        }
+
+        // Skip nodes that have been reached in fewer commands after they had been queued.
+        let key = (node.ship.pos(), node.ship.heading());
+        if best.get(&key).is_some_and(|&len| len < node.len) || node.len >= max_len {
+            continue;
+        }
+
+        let len = node.len + 1;
+        for &command in alphabet {
+            // No target lies outside the range of an `i32`, so commands leaving it never need
+            // to be searched.
+            let mut ship = nodes[index].ship.clone();
+            if ship.checked_set_course(command).is_none() {
+                continue;
+            }
+
+            let remaining = estimate(&ship);
+            if remaining == usize::MAX || len + remaining > max_len {
+                continue;
+            }
+
+            let key = (ship.pos(), ship.heading());
+            if best.get(&key).is_some_and(|&known| known <= len) {
+                continue;
+            }
+            best.insert(key, len);
+
+            nodes.push(Node {
+                ship,
+                len,
+                parent: Some((index, command)),
+            });
+            open.push(Reverse((len + remaining, nodes.len() - 1)));
+        }
+    }
+
+    None
+}
+
+/// Follow the parents of a node back to the start to find the commands that lead to it.
+fn reconstruct(nodes: &[Node], mut index: usize) -> Vec<CourseCommand> {
+    let mut commands = Vec::with_capacity(nodes[index].len);
+    while let Some((parent, command)) = nodes[index].parent {
+        commands.push(command);
+        index = parent;
+    }
+
+    commands.reverse();
+    commands
+}
@@ -0,0 +1,133 @@
+//! The error type shared by all days, pointing to the place in the input that caused it.
+
+use std::fmt;
+use std::io;
+use std::num::ParseIntError;
+
+use crate::day02::CharPolicyParseErr;
+use crate::day03::TerrainParseError;
+use crate::day04::PassportParseErr;
+use crate::day05::BoardingInfoParseErr;
+use crate::day06::AnswersParseErr;
+use crate::day07::BagRuleParseErr;
+use crate::day08::OperationParseError;
+use crate::day10::AdapterParseError;
+use crate::day11::TileMapParseError;
+use crate::day12::CourseCommandParseError;
+use crate::runner::Part;
+
+/// A position in the puzzle input. Both line and column start counting at one.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Location {
+    /// Find the line and column of the character at the byte offset in the input.
+    pub fn of_offset(input: &str, offset: usize) -> Self {
+        let before = &input[..offset];
+        let line_start = match before.rfind('\n') {
+            Some(newline) => newline + 1,
+            None => 0,
+        };
+
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+/// Everything that can go wrong while solving a puzzle.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("unable to read input: {0}")]
+    Io(#[from] io::Error),
+    #[error("{location}: {kind}")]
+    Parse {
+        location: Location,
+        kind: ParseError,
+    },
+    #[error("the input has no solution for part {0}")]
+    NoSolution(Part),
+}
+
+/// The reason a part of the input could not be parsed.
+#[derive(Debug, thiserror::Error)]
+pub enum ParseError {
+    #[error("{0}")]
+    Int(#[from] ParseIntError),
+    #[error(transparent)]
+    CharPolicy(#[from] CharPolicyParseErr),
+    #[error(transparent)]
+    Terrain(#[from] TerrainParseError),
+    #[error(transparent)]
+    Passport(#[from] PassportParseErr),
+    #[error(transparent)]
+    BoardingInfo(#[from] BoardingInfoParseErr),
+    #[error(transparent)]
+    Answers(#[from] AnswersParseErr),
+    #[error(transparent)]
+    BagRule(#[from] BagRuleParseErr),
+    #[error(transparent)]
+    Operation(#[from] OperationParseError),
+    #[error(transparent)]
+    Adapter(#[from] AdapterParseError),
+    #[error(transparent)]
+    TileMap(#[from] TileMapParseError),
+    #[error(transparent)]
+    CourseCommand(#[from] CourseCommandParseError),
+}
+
+impl ParseError {
+    /// The byte offset of the error inside the string that was being parsed, if the error knows
+    /// where exactly it occurred. Otherwise it points to the start of the string.
+    pub fn offset(&self) -> usize {
+        match self {
+            Self::Terrain(TerrainParseError::UnknownToken(_, offset))
+            | Self::Terrain(TerrainParseError::EmptyRow(offset))
+            | Self::Terrain(TerrainParseError::RowLength(_, _, offset))
+            | Self::BoardingInfo(BoardingInfoParseErr::InvalidDirection(_, offset))
+            | Self::Answers(AnswersParseErr::UnexpectedCharacter(_, offset))
+            | Self::Answers(AnswersParseErr::DoubledAnswer(_, offset))
+            | Self::TileMap(TileMapParseError::UnknownTile(_, offset))
+            | Self::TileMap(TileMapParseError::RowLength(_, _, offset)) => *offset,
+            _ => 0,
+        }
+    }
+}
+
+impl Error {
+    /// Create a parse error for a failure while parsing `part`, which must be a slice of `input`.
+    /// The location is calculated from where `part` lies in the input.
+    pub fn parse(input: &str, part: &str, kind: impl Into<ParseError>) -> Self {
+        let kind = kind.into();
+        let part_offset = part.as_ptr() as usize - input.as_ptr() as usize;
+        assert!(part_offset + part.len() <= input.len());
+
+        Self::Parse {
+            location: Location::of_offset(input, part_offset + kind.offset()),
+            kind,
+        }
+    }
+}
+
+/// Parse every line of the input with the provided function, attaching the location to the
+/// first error that occurs.
+pub fn parse_lines<T, E, F>(input: &str, mut parse: F) -> Result<Vec<T>, Error>
+where
+    E: Into<ParseError>,
+    F: FnMut(&str) -> Result<T, E>,
+{
+    input
+        .lines()
+        .map(|line| parse(line).map_err(|err| Error::parse(input, line, err)))
+        .collect()
+}
@@ -0,0 +1,143 @@
+//! Rectangular grids of cells, shared by the puzzles that move around on a map.
+
+use crate::vector::Vec2;
+
+/// What lies beyond the edges of a grid.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Topology {
+    /// Nothing, positions beyond the edges are not on the grid.
+    #[default]
+    Bounded,
+    /// The grid repeats to the left and to the right, but ends at the top and the bottom.
+    WrapHorizontal,
+    /// The grid repeats in every direction.
+    Toroidal,
+}
+
+impl Topology {
+    pub fn wraps_horizontally(self) -> bool {
+        matches!(self, Self::WrapHorizontal | Self::Toroidal)
+    }
+
+    pub fn wraps_vertically(self) -> bool {
+        matches!(self, Self::Toroidal)
+    }
+}
+
+/// Bring a coordinate back into `0..len`, if the grid wraps along its axis.
+fn wrap_coordinate(coordinate: isize, len: usize, wraps: bool) -> Option<usize> {
+    if coordinate >= 0 && (coordinate as usize) < len {
+        Some(coordinate as usize)
+    } else if wraps && len > 0 {
+        Some(coordinate.rem_euclid(len as isize) as usize)
+    } else {
+        None
+    }
+}
+
+/// A rectangular grid of cells, stored row by row in one contiguous buffer.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+    topology: Topology,
+}
+
+impl<T: Clone> Grid<T> {
+    /// Create a grid where every cell has the same value.
+    pub fn filled((width, height): (usize, usize), value: T, topology: Topology) -> Self {
+        Self::from_cells(vec![value; width * height], (width, height), topology)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Create a grid from its cells, one row after the other. Panics if the number of cells does
+    /// not match the size.
+    pub fn from_cells(cells: Vec<T>, (width, height): (usize, usize), topology: Topology) -> Self {
+        assert_eq!(cells.len(), width * height);
+        Self {
+            cells,
+            width,
+            height,
+            topology,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn size(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    pub fn topology(&self) -> Topology {
+        self.topology
+    }
+
+    pub fn set_topology(&mut self, topology: Topology) {
+        self.topology = topology;
+    }
+
+    /// The cells of the grid, one row after the other.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Bring a position onto the grid by wrapping it around the edges the topology connects.
+    /// Returns `None` if the position is beyond an edge that does not wrap.
+    pub fn wrap(&self, pos: Vec2<isize>) -> Option<Vec2<usize>> {
+        Some(Vec2::new(
+            wrap_coordinate(pos.x, self.width, self.topology.wraps_horizontally())?,
+            wrap_coordinate(pos.y, self.height, self.topology.wraps_vertically())?,
+        ))
+    }
+
+    /// The position reached when moving from the position by the offset, wrapped onto the grid.
+    pub fn offset(&self, pos: Vec2<usize>, offset: Vec2<isize>) -> Option<Vec2<usize>> {
+        self.wrap(Vec2::new(
+            pos.x as isize + offset.x,
+            pos.y as isize + offset.y,
+        ))
+    }
+
+    /// The index of the cell at the position in `cells`. Positions beyond an edge that wraps are
+    /// wrapped onto the grid first.
+    pub fn index(&self, pos: Vec2<usize>) -> Option<usize> {
+        let pos = if pos.x < self.width && pos.y < self.height {
+            pos
+        } else {
+            self.offset(pos, Vec2::new(0, 0))?
+        };
+
+        Some(pos.y * self.width + pos.x)
+    }
+
+    /// The position of the cell with the index in `cells`.
+    pub fn position(&self, index: usize) -> Vec2<usize> {
+        Vec2::new(index % self.width, index / self.width)
+    }
+
+    pub fn get(&self, pos: Vec2<usize>) -> Option<&T> {
+        let index = self.index(pos)?;
+        self.cells.get(index)
+    }
+
+    pub fn get_mut(&mut self, pos: Vec2<usize>) -> Option<&mut T> {
+        let index = self.index(pos)?;
+        self.cells.get_mut(index)
+    }
+}
@@ -0,0 +1,71 @@
+//! Reading puzzle input from wherever the user chose to provide it.
+
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+
+/// Environment variable that can be set to the directory the input files are read from. If it is
+/// not set, they are read from `input` relative to the working directory.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Argument that reads the input from stdin instead of a file.
+pub const STDIN_ARG: &str = "-";
+
+/// Where the puzzle input of a day is read from.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum InputSource {
+    /// Read the input from the file at this path.
+    Path(PathBuf),
+    /// Read the input from stdin until it is closed.
+    Stdin,
+    /// Read the input file of this day from the input directory.
+    Day(u8),
+}
+
+impl InputSource {
+    /// Interpret a command line argument as input source. `-` stands for stdin, everything else
+    /// is a path.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == STDIN_ARG {
+            Self::Stdin
+        } else {
+            Self::Path(PathBuf::from(arg))
+        }
+    }
+
+    /// Read the input source given as the first command line argument of the process, falling
+    /// back to the input file of the day if there is none.
+    pub fn from_env_args(day: u8) -> Self {
+        match env::args().nth(1) {
+            Some(arg) => Self::from_arg(&arg),
+            None => Self::Day(day),
+        }
+    }
+
+    /// Read the entire input from this source.
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            Self::Path(path) => fs::read_to_string(path),
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            Self::Day(day) => fs::read_to_string(day_input_path(*day)),
+        }
+    }
+}
+
+/// The directory input files are read from, as configured by `INPUT_DIR_VAR`.
+pub fn input_dir() -> PathBuf {
+    match env::var_os(INPUT_DIR_VAR) {
+        Some(dir) => PathBuf::from(dir),
+        None => PathBuf::from("input"),
+    }
+}
+
+/// The path of the input file of a day inside the input directory.
+pub fn day_input_path(day: u8) -> PathBuf {
+    input_dir().join(format!("{:02}", day))
+}
@@ -0,0 +1,47 @@
+//! Solutions for the Advent of Code 2020 puzzles.
+//!
+//! Every day lives in its own module containing the types used to model that day's puzzle and a
+//! unit struct implementing [`Solution`], so the puzzles can be solved from other tools without
+//! going through the binaries.
+
+use std::fmt::Display;
+
+pub use error::Error;
+
+pub mod automaton;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod runner;
+pub mod vector;
+
+/// The common shape of every puzzle: The input is parsed once and then used to solve both parts.
+pub trait Solution {
+    /// The parsed puzzle input that is shared by both parts.
+    type Input;
+    /// The answer for the first part of the puzzle.
+    type AnswerA: Display;
+    /// The answer for the second part of the puzzle.
+    type AnswerB: Display;
+
+    /// Parse the raw puzzle input.
+    fn parse(input: &str) -> Result<Self::Input, Error>;
+
+    /// Solve the first part of the puzzle. Returns `None` if the input has no solution.
+    fn part_a(input: &Self::Input) -> Option<Self::AnswerA>;
+
+    /// Solve the second part of the puzzle. Returns `None` if the input has no solution.
+    fn part_b(input: &Self::Input) -> Option<Self::AnswerB>;
+}
@@ -0,0 +1,122 @@
+//! Type erased access to the solutions of every day, so they can be run from one place.
+
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use crate::day01::Day01;
+use crate::day02::Day02;
+use crate::day03::Day03;
+use crate::day04::Day04;
+use crate::day05::Day05;
+use crate::day06::Day06;
+use crate::day07::Day07;
+use crate::day08::Day08;
+use crate::day09::Day09;
+use crate::day10::Day10;
+use crate::day11::Day11;
+use crate::day12::Day12;
+use crate::{Error, Solution};
+
+/// One of the two parts every puzzle consists of.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::A, Part::B];
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "a" | "A" => Ok(Self::A),
+            "b" | "B" => Ok(Self::B),
+            other => Err(format!("{} is not a part, expected a or b", other)),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::A => write!(f, "a"),
+            Self::B => write!(f, "b"),
+        }
+    }
+}
+
+/// The result of running one part of a puzzle.
+#[derive(Clone, Debug)]
+pub struct PartOutcome {
+    pub part: Part,
+    /// The answer, or `None` if the input has no solution for this part.
+    pub answer: Option<String>,
+    /// The time it took to solve the part, not including parsing the input.
+    pub time: Duration,
+}
+
+/// The result of running a day on one input.
+#[derive(Clone, Debug)]
+pub struct DayOutcome {
+    /// The time it took to parse the input.
+    pub parse_time: Duration,
+    pub parts: Vec<PartOutcome>,
+}
+
+/// Parses the input and solves the requested parts of a day. Returns an error if the input could not
+/// be parsed.
+pub type DayRunner = fn(&str, &[Part]) -> Result<DayOutcome, Error>;
+
+/// All days that have a solution, in order.
+pub const DAYS: [(u8, DayRunner); 12] = [
+    (1, run::<Day01>),
+    (2, run::<Day02>),
+    (3, run::<Day03>),
+    (4, run::<Day04>),
+    (5, run::<Day05>),
+    (6, run::<Day06>),
+    (7, run::<Day07>),
+    (8, run::<Day08>),
+    (9, run::<Day09>),
+    (10, run::<Day10>),
+    (11, run::<Day11>),
+    (12, run::<Day12>),
+];
+
+/// Find the runner of a day, if that day has been solved.
+pub fn find_day(day: u8) -> Option<DayRunner> {
+    DAYS.iter()
+        .find(|(registered, _)| *registered == day)
+        .map(|(_, runner)| *runner)
+}
+
+/// Parse the input and solve the requested parts of the solution `S`, timing each step.
+pub fn run<S: Solution>(input: &str, parts: &[Part]) -> Result<DayOutcome, Error> {
+    let start = Instant::now();
+    let input = S::parse(input)?;
+    let parse_time = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                Part::A => S::part_a(&input).map(|answer| answer.to_string()),
+                Part::B => S::part_b(&input).map(|answer| answer.to_string()),
+            };
+
+            PartOutcome {
+                part,
+                answer,
+                time: start.elapsed(),
+            }
+        })
+        .collect();
+
+    Ok(DayOutcome { parse_time, parts })
+}
@@ -0,0 +1,167 @@
+//! Two-dimensional positions and offsets shared by the grid and navigation puzzles.
+
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// A position or offset on a two-dimensional grid.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Vec2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> From<(T, T)> for Vec2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> From<Vec2<T>> for (T, T) {
+    fn from(vec: Vec2<T>) -> Self {
+        (vec.x, vec.y)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Vec2<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Vec2<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Vec2<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: SubAssign> SubAssign for Vec2<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Vec2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+/// Scaling by a scalar factor.
+impl<T: Mul<Output = T> + Copy> Mul<T> for Vec2<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        Self::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl<T: MulAssign + Copy> MulAssign<T> for Vec2<T> {
+    fn mul_assign(&mut self, factor: T) {
+        self.x *= factor;
+        self.y *= factor;
+    }
+}
+
+macro_rules! impl_signed_vec2 {
+    ($($t:ty),*) => {$(
+        impl Vec2<$t> {
+            /// The distance when only moving along the axes.
+            pub fn manhattan(self, other: Self) -> $t {
+                (self.x - other.x).abs() + (self.y - other.y).abs()
+            }
+
+            /// Like `manhattan`, but returns `None` if the distance does not fit into the type.
+            pub fn checked_manhattan(self, other: Self) -> Option<$t> {
+                let dx = self.x.checked_sub(other.x)?.checked_abs()?;
+                let dy = self.y.checked_sub(other.y)?.checked_abs()?;
+                dx.checked_add(dy)
+            }
+
+            /// Add both coordinates, returning `None` if either of them overflows.
+            pub fn checked_add(self, other: Self) -> Option<Self> {
+                Some(Self::new(
+                    self.x.checked_add(other.x)?,
+                    self.y.checked_add(other.y)?,
+                ))
+            }
+
+            /// Multiply both coordinates, returning `None` if either of them overflows.
+            pub fn checked_mul(self, factor: $t) -> Option<Self> {
+                Some(Self::new(
+                    self.x.checked_mul(factor)?,
+                    self.y.checked_mul(factor)?,
+                ))
+            }
+
+            /// The distance when diagonal steps are as long as steps along the axes.
+            pub fn chebyshev(self, other: Self) -> $t {
+                (self.x - other.x).abs().max((self.y - other.y).abs())
+            }
+
+            /// The straight line distance.
+            pub fn euclidean(self, other: Self) -> f64 {
+                ((self.x - other.x) as f64).hypot((self.y - other.y) as f64)
+            }
+
+            /// Rotate around the origin by a number of quarter turns. One positive quarter turn
+            /// rotates the positive x axis onto the positive y axis.
+            pub fn rotated(self, quarter_turns: i32) -> Self {
+                match quarter_turns.rem_euclid(4) {
+                    0 => self,
+                    1 => Self::new(-self.y, self.x),
+                    2 => Self::new(-self.x, -self.y),
+                    _ => Self::new(self.y, -self.x),
+                }
+            }
+        }
+    )*};
+}
+
+impl_signed_vec2!(i32, i64, isize);
+
+impl Vec2<f64> {
+    /// The straight line distance.
+    pub fn euclidean(self, other: Self) -> f64 {
+        (self.x - other.x).hypot(self.y - other.y)
+    }
+
+    /// Rotate around the origin by an angle in radians. Positive angles rotate the positive x
+    /// axis towards the positive y axis.
+    pub fn rotated(self, rad: f64) -> Self {
+        let (sin, cos) = rad.sin_cos();
+        Self::new(self.x * cos - self.y * sin, self.x * sin + self.y * cos)
+    }
+}
+
+impl Vec2<usize> {
+    /// Move the position by an offset. Returns `None` if the result would have a negative
+    /// coordinate.
+    pub fn checked_offset(self, offset: Vec2<isize>) -> Option<Self> {
+        let x = self.x as isize + offset.x;
+        let y = self.y as isize + offset.y;
+        if x >= 0 && y >= 0 {
+            Some(Self::new(x as usize, y as usize))
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use std::collections::BTreeSet;
+
+use advent2020::automaton::{Hexagonal, Moore, Neighbourhood, Settlement, SparseAutomaton};
+use advent2020::day11::{
+    run_incremental_a, run_incremental_b, SeatSpace, Seating, Tile, TileMap, RULE_A, RULE_B,
+};
+use advent2020::vector::Vec2;
+
+/// Active cells stay active with two or three active neighbours, inactive ones become active with
+/// exactly three.
+fn conway(active: bool, neighbours: usize) -> bool {
+    matches!((active, neighbours), (true, 2) | (_, 3))
+}
+
+/// The active cells of the example slice, placed in `N` dimensions.
+fn glider<const N: usize>() -> BTreeSet<[i64; N]> {
+    let mut active = BTreeSet::new();
+    for (y, line) in [".#.", "..#", "###"].iter().enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            if c == '#' {
+                let mut cell = [0; N];
+                cell[0] = x as i64;
+                cell[1] = y as i64;
+                active.insert(cell);
+            }
+        }
+    }
+
+    active
+}
+
+#[test]
+fn conway_cubes_in_three_and_four_dimensions() {
+    let automaton = SparseAutomaton::new(Moore::<3>, conway);
+    let mut active = glider::<3>();
+    for _ in 0..6 {
+        active = automaton.step(&active);
+    }
+    assert_eq!(active.len(), 112);
+
+    let automaton = SparseAutomaton::new(Moore::<4>, conway);
+    let mut active = glider::<4>();
+    for _ in 0..6 {
+        active = automaton.step(&active);
+    }
+    assert_eq!(active.len(), 848);
+}
+
+#[test]
+fn hexagonal_seats_settle() {
+    // A hexagon with its six neighbours. The centre is left as soon as all seats are taken, while
+    // the outer seats only ever see three taken seats.
+    let mut seats = vec![Vec2::new(0, 0)];
+    seats.extend(Hexagonal::DIRECTIONS.iter().copied());
+    let automaton = SparseAutomaton::with_seats(Hexagonal, seating_rule, seats);
+
+    let settlement = automaton.simulate(BTreeSet::new(), 100);
+    let ring: BTreeSet<_> = Hexagonal::DIRECTIONS.iter().copied().collect();
+    assert_eq!(
+        settlement,
+        Settlement::FixedPoint {
+            map: ring,
+            generations: 2
+        }
+    );
+}
+
+fn seating_rule(occupied: bool, neighbours: usize) -> bool {
+    let tile = if occupied {
+        Tile::SeatTaken
+    } else {
+        Tile::SeatEmpty
+    };
+    RULE_A.next_tile(tile, neighbours).occupied()
+}
+
+#[test]
+fn sparse_seats_match_tile_maps() {
+    let map: TileMap = "L.LL.LL.LL\nLLLLLLL.LL\nL.L.L..L..\nLLLL.LL.LL\nL.LL.LL.LL\n\
+        L.LLLLL.LL\n..L.L.....\nLLLLLLLLLL\nL.LLLLLL.L\nL.LLLLL.LL"
+        .parse()
+        .unwrap();
+
+    let (width, _) = map.size();
+    let seats = map
+        .tiles()
+        .iter()
+        .enumerate()
+        .filter(|(_, &tile)| tile != Tile::Floor)
+        .map(|(i, _)| [(i % width) as i64, (i / width) as i64]);
+    let automaton = SparseAutomaton::with_seats(Moore::<2>, seating_rule, seats);
+
+    let stable = run_incremental_a(&map).unwrap();
+    match automaton.simulate(BTreeSet::new(), 100) {
+        Settlement::FixedPoint { map, generations } => {
+            assert_eq!(map.len(), stable.map.num_occupied());
+            assert_eq!(generations, stable.generations);
+        }
+        other => panic!("seats did not settle: {:?}", other),
+    }
+}
+
+#[test]
+fn seat_spaces_run_day_11_rules_in_sparse_automatons() {
+    let map: TileMap = "L.LL.LL.LL\nLLLLLLL.LL\nL.L.L..L..\nLLLL.LL.LL\nL.LL.LL.LL\n\
+        L.LLLLL.LL\n..L.L.....\nLLLLLLLLLL\nL.LLLLLL.L\nL.LLLLL.LL"
+        .parse()
+        .unwrap();
+
+    let seats: Vec<usize> = (0..map.tiles().len())
+        .filter(|&i| map.tiles()[i] != Tile::Floor)
+        .collect();
+    let space = SeatSpace::new(&map, RULE_B.neighbourhood);
+    let seating = Seating::new(&map, RULE_B);
+    for &seat in &seats {
+        assert_eq!(space.neighbours(seat), seating.neighbours(seat));
+    }
+
+    let rule = |occupied: bool, neighbours: usize| {
+        let tile = if occupied {
+            Tile::SeatTaken
+        } else {
+            Tile::SeatEmpty
+        };
+        RULE_B.next_tile(tile, neighbours).occupied()
+    };
+    let automaton = SparseAutomaton::with_seats(space, rule, seats);
+
+    let stable = run_incremental_b(&map).unwrap();
+    match automaton.simulate(BTreeSet::new(), 100) {
+        Settlement::FixedPoint { map, generations } => {
+            assert_eq!(map.len(), 26);
+            assert_eq!(map.len(), stable.map.num_occupied());
+            assert_eq!(generations, stable.generations);
+        }
+        other => panic!("seats did not settle: {:?}", other),
+    }
+}
@@ -0,0 +1,55 @@
+//! The generated inputs of the benchmarks must be solvable, or the benchmarks would measure
+//! nothing or never finish.
+
+#[path = "../benches/generate/mod.rs"]
+#[allow(dead_code)]
+mod generate;
+
+use advent2020::day01::Day01;
+use advent2020::day02::Day02;
+use advent2020::day03::Day03;
+use advent2020::day04::Day04;
+use advent2020::day05::Day05;
+use advent2020::day06::Day06;
+use advent2020::day07::Day07;
+use advent2020::day08::Day08;
+use advent2020::day09::Day09;
+use advent2020::day10::Day10;
+use advent2020::day11::{run_incremental_a, run_incremental_b, Day11};
+use advent2020::day12::Day12;
+use advent2020::Solution;
+
+/// Parse the generated input and solve both parts, which must have an answer.
+fn solve<S: Solution>(day: &str, input: &str) {
+    let parsed = S::parse(input).unwrap_or_else(|err| panic!("invalid {} input: {}", day, err));
+    assert!(S::part_a(&parsed).is_some(), "{} has no answer for a", day);
+    assert!(S::part_b(&parsed).is_some(), "{} has no answer for b", day);
+}
+
+#[test]
+fn generated_inputs_have_answers() {
+    let size = generate::DEFAULT_BENCH_SIZE;
+    solve::<Day01>("day01", &generate::day01(size));
+    solve::<Day02>("day02", &generate::day02(size));
+    solve::<Day03>("day03", &generate::day03(size));
+    solve::<Day04>("day04", &generate::day04(size));
+    solve::<Day05>("day05", &generate::day05(size));
+    solve::<Day06>("day06", &generate::day06(size));
+    solve::<Day07>("day07", &generate::day07(size));
+    solve::<Day08>("day08", &generate::day08(size));
+    solve::<Day09>("day09", &generate::day09(size));
+    solve::<Day10>("day10", &generate::day10(size));
+    solve::<Day12>("day12", &generate::day12(size));
+}
+
+#[test]
+fn generated_seat_layouts_settle() {
+    // The first layouts drawn for sizes 47, 50, 113 and 204 oscillate under the rule of part a.
+    let sizes = (0..=20).chain([47, 50, 64, 113, generate::DEFAULT_BENCH_SIZE, 204]);
+    for size in sizes {
+        let map = Day11::parse(&generate::day11(size)).unwrap();
+        assert_eq!(map.size(), (if size == 0 { 0 } else { 100 }, size));
+        assert!(run_incremental_a(&map).is_some(), "size {}", size);
+        assert!(run_incremental_b(&map).is_some(), "size {}", size);
+    }
+}
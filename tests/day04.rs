@@ -0,0 +1,46 @@
+use std::str::FromStr;
+
+use advent2020::day04::{Passport, PassportValueErr};
+
+fn passport(s: &str) -> Passport {
+    Passport::from_str(s).unwrap()
+}
+
+#[test]
+fn valid_passports_pass_all_checks() {
+    let valid = [
+        "pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980 hcl:#623a2f",
+        "eyr:2029 ecl:blu cid:129 byr:1989 iyr:2014 pid:896056539 hcl:#a97842 hgt:165cm",
+        "hcl:#888785 hgt:164cm byr:2001 iyr:2015 cid:88 pid:545766238 ecl:hzl eyr:2022",
+    ];
+    for s in &valid {
+        assert_eq!(passport(s).check_values(), Ok(()), "{}", s);
+    }
+}
+
+#[test]
+fn invalid_passports_report_the_first_invalid_value() {
+    let invalid = [
+        (
+            "eyr:1972 cid:100 hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926",
+            PassportValueErr::ExpirationYear("1972".to_owned()),
+        ),
+        (
+            "iyr:2019 hcl:#602927 eyr:1967 hgt:170cm ecl:grn pid:012533040 byr:1946",
+            PassportValueErr::ExpirationYear("1967".to_owned()),
+        ),
+        (
+            "hcl:dab227 iyr:2012 ecl:brn hgt:182cm pid:021572410 eyr:2020 byr:1992 cid:277",
+            PassportValueErr::HairColour("dab227".to_owned()),
+        ),
+        (
+            "hgt:59cm ecl:zzz eyr:2038 hcl:74454a iyr:2023 pid:3556412378 byr:2007",
+            PassportValueErr::BirthYear("2007".to_owned()),
+        ),
+    ];
+    for (s, err) in &invalid {
+        let passport = passport(s);
+        assert_eq!(passport.check_values().as_ref(), Err(err), "{}", s);
+        assert!(!passport.values_ok());
+    }
+}
@@ -0,0 +1,73 @@
+use advent2020::day10::{AdapterChain, AdapterParseError, Day10, MAX_JOLTAGE_STEP};
+use advent2020::Solution;
+
+const SMALL_EXAMPLE: &str = "16\n10\n15\n5\n1\n11\n7\n19\n6\n12\n4\n";
+
+const LARGE_EXAMPLE: &str = "28\n33\n18\n42\n31\n14\n46\n20\n48\n47\n24\n23\n49\n45\n19\n38\n\
+                             39\n11\n1\n32\n25\n35\n8\n17\n7\n9\n4\n2\n34\n10\n3\n";
+
+#[test]
+fn small_example() {
+    let chain = Day10::parse(SMALL_EXAMPLE).unwrap();
+    assert_eq!(Day10::part_a(&chain), Some(7 * 5));
+    assert_eq!(Day10::part_b(&chain), Some(8));
+}
+
+#[test]
+fn large_example() {
+    let chain = Day10::parse(LARGE_EXAMPLE).unwrap();
+    assert_eq!(Day10::part_a(&chain), Some(22 * 10));
+    assert_eq!(Day10::part_b(&chain), Some(19208));
+}
+
+#[test]
+fn long_chains_are_counted_without_exploring_every_arrangement() {
+    // Adapters that are three jolts apart can only be arranged in one way, however many there are.
+    let chain = AdapterChain::new((1..=100_000).map(|i| 3 * i).collect()).unwrap();
+    assert_eq!(chain.num_arrangements(), Some(1));
+
+    // With adapters one jolt apart the arrangements grow like the tribonacci numbers.
+    let chain = AdapterChain::new((1..=100).collect()).unwrap();
+    let mut tribonacci: [u128; 3] = [0, 0, 1];
+    for _ in 0..=100 {
+        tribonacci = [tribonacci[1], tribonacci[2], tribonacci.iter().sum()];
+    }
+    assert_eq!(chain.num_arrangements(), Some(tribonacci[1]));
+}
+
+#[test]
+fn too_many_arrangements_for_u128() {
+    let chain = AdapterChain::new((1..=200).collect()).unwrap();
+    assert_eq!(chain.num_arrangements(), None);
+    assert_eq!(Day10::part_b(&chain), None);
+}
+
+#[test]
+fn gaps_between_adapters_have_no_solution() {
+    let chain = AdapterChain::new(vec![1, 2, 6]).unwrap();
+    assert_eq!(chain.differences(), None);
+    assert_eq!(Day10::part_a(&chain), None);
+}
+
+#[test]
+fn adapters_without_room_for_the_device_are_rejected() {
+    let input = format!("1\n{}\n4\n", u64::MAX - 1);
+    let err = Day10::parse(&input).err().unwrap();
+    assert_eq!(
+        err.to_string(),
+        format!(
+            "line 2, column 1: adapter rated {} jolts leaves no room for a device rated 3 jolts higher",
+            u64::MAX - 1
+        )
+    );
+
+    let highest = u64::MAX - MAX_JOLTAGE_STEP;
+    let chain = Day10::parse(&format!("{}\n", highest)).unwrap();
+    assert_eq!(chain.joltages(), &[0, highest, u64::MAX]);
+    assert_eq!(Day10::part_a(&chain), None);
+
+    assert!(matches!(
+        AdapterChain::new(vec![u64::MAX]),
+        Err(AdapterParseError::NoRoomForDevice(u64::MAX))
+    ));
+}
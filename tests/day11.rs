@@ -0,0 +1,420 @@
+use advent2020::day11::bitboard::{self, BitMap, UnsupportedTopology};
+use advent2020::day11::report::SeatReport;
+use advent2020::day11::{
+    perform_step_a, perform_step_b, run_incremental_a, run_incremental_b, run_until_stable, Day11,
+    SeatMap, SeatNeighbourhood, Seating, SeatingRule, Settlement, Tile, TileMap, MAX_GENERATIONS,
+    RULE_A, RULE_B,
+};
+#[cfg(feature = "parallel")]
+use advent2020::day11::{
+    perform_step_a_parallel, perform_step_a_parallel_with, perform_step_b_parallel,
+    perform_step_b_parallel_with,
+};
+use advent2020::grid::Topology;
+use advent2020::runner::Part;
+use advent2020::vector::Vec2;
+use advent2020::Solution;
+use std::convert::TryFrom;
+use std::fmt::Debug;
+use std::str::FromStr;
+
+#[path = "../benches/generate/mod.rs"]
+#[allow(dead_code)]
+mod generate;
+
+/// Generate a seat layout with the generator of the benchmarks, so every run of the test uses the
+/// same maps.
+fn random_map(seed: u64, width: usize, height: usize) -> TileMap {
+    let mut rng = generate::Rng::new(seed);
+    let mut map = String::with_capacity((width + 1) * height);
+    for _ in 0..height {
+        for _ in 0..width {
+            map.push(match rng.below(4) {
+                0 => '.',
+                1 => '#',
+                _ => 'L',
+            });
+        }
+        map.push('\n');
+    }
+
+    map.parse().unwrap()
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn parallel_steps_match_sequential_steps() {
+    for (seed, width, height) in [(1, 90, 97), (2, 13, 200), (3, 150, 31), (4, 0, 0)] {
+        let map = random_map(seed, width, height);
+
+        assert_eq!(
+            run_until_stable(&map, MAX_GENERATIONS, perform_step_a_parallel).unwrap(),
+            run_until_stable(&map, MAX_GENERATIONS, perform_step_a).unwrap()
+        );
+        assert_eq!(
+            run_until_stable(&map, MAX_GENERATIONS, perform_step_b_parallel).unwrap(),
+            run_until_stable(&map, MAX_GENERATIONS, perform_step_b).unwrap()
+        );
+
+        let seating = Seating::new(&map, RULE_B);
+        assert_eq!(
+            run_until_stable(
+                &map,
+                MAX_GENERATIONS,
+                |source: &TileMap, target: &mut TileMap| {
+                    seating.perform_step_parallel(source, target)
+                }
+            )
+            .unwrap(),
+            run_until_stable(&map, MAX_GENERATIONS, perform_step_b).unwrap()
+        );
+    }
+}
+
+// The number of threads `perform_step_a_parallel` picks depends on the machine, so the bands are
+// also forced here, including more threads than rows and bands of uneven height.
+#[cfg(feature = "parallel")]
+#[test]
+fn steps_split_into_bands_match_sequential_steps() {
+    for (seed, width, height) in [(9, 40, 37), (10, 7, 3), (11, 1, 1), (12, 0, 0)] {
+        let map = random_map(seed, width, height);
+        let seating = Seating::new(&map, RULE_B);
+
+        for num_threads in [0, 2, 3, 8, 64] {
+            assert_eq!(
+                run_until_stable(
+                    &map,
+                    MAX_GENERATIONS,
+                    |source: &TileMap, target: &mut TileMap| {
+                        perform_step_a_parallel_with(source, target, num_threads)
+                    }
+                )
+                .unwrap(),
+                run_until_stable(&map, MAX_GENERATIONS, perform_step_a).unwrap()
+            );
+            assert_eq!(
+                run_until_stable(
+                    &map,
+                    MAX_GENERATIONS,
+                    |source: &TileMap, target: &mut TileMap| {
+                        perform_step_b_parallel_with(source, target, num_threads)
+                    }
+                )
+                .unwrap(),
+                run_until_stable(&map, MAX_GENERATIONS, perform_step_b).unwrap()
+            );
+            assert_eq!(
+                run_until_stable(
+                    &map,
+                    MAX_GENERATIONS,
+                    |source: &TileMap, target: &mut TileMap| {
+                        seating.perform_step_parallel_with(source, target, num_threads)
+                    }
+                )
+                .unwrap(),
+                run_until_stable(&map, MAX_GENERATIONS, perform_step_b).unwrap()
+            );
+        }
+    }
+}
+
+#[test]
+fn incremental_runs_match_full_steps() {
+    for (seed, width, height) in [(5, 90, 97), (6, 1, 50), (7, 0, 0)] {
+        let map = random_map(seed, width, height);
+
+        let stable = run_incremental_a(&map).unwrap();
+        assert_eq!(
+            stable.map,
+            run_until_stable(&map, MAX_GENERATIONS, perform_step_a).unwrap()
+        );
+        let stable = run_incremental_b(&map).unwrap();
+        assert_eq!(
+            stable.map,
+            run_until_stable(&map, MAX_GENERATIONS, perform_step_b).unwrap()
+        );
+    }
+}
+
+#[test]
+fn adjacent_rule_matches_radius_one() {
+    let map = random_map(8, 60, 40);
+    let rule = SeatingRule {
+        neighbourhood: SeatNeighbourhood::Radius(1),
+        occupy_threshold: 0,
+        vacate_threshold: 4,
+    };
+
+    let seating = Seating::new(&map, rule);
+    assert_eq!(
+        seating.run_incremental(&map, MAX_GENERATIONS).unwrap().map,
+        run_until_stable(&map, MAX_GENERATIONS, perform_step_a).unwrap()
+    );
+    assert_eq!(
+        seating.run_incremental(&map, MAX_GENERATIONS).unwrap().map,
+        run_until_stable(
+            &map,
+            MAX_GENERATIONS,
+            |source: &TileMap, target: &mut TileMap| { seating.perform_step(source, target) }
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn simulation_finds_fixed_points_and_cycles() {
+    let map = random_map(9, 40, 40);
+    let stable = run_incremental_a(&map).unwrap();
+    assert_eq!(
+        Seating::new(&map, RULE_A).simulate(&map, 1000),
+        Settlement::FixedPoint {
+            map: stable.map,
+            generations: stable.generations
+        }
+    );
+
+    // Both seats are taken because no neighbour is, then left because one is, and so on.
+    let map: TileMap = "LL".parse().unwrap();
+    let blinking = Seating::new(
+        &map,
+        SeatingRule {
+            neighbourhood: SeatNeighbourhood::Adjacent,
+            occupy_threshold: 0,
+            vacate_threshold: 1,
+        },
+    );
+    assert_eq!(
+        blinking.simulate(&map, 1000),
+        Settlement::Cycle {
+            map: map.clone(),
+            start: 0,
+            length: 2
+        }
+    );
+    assert_eq!(
+        blinking.simulate(&map, 1),
+        Settlement::LimitReached {
+            map: "##".parse().unwrap()
+        }
+    );
+}
+
+#[test]
+fn oscillating_maps_have_no_solution() {
+    // Every seat has at least four others around it, so all seats are taken in one generation
+    // and left in the next.
+    let map = Day11::parse(".LL.\nLLLL\nLLLL\n.LL.\n").unwrap();
+    assert!(matches!(
+        Seating::new(&map, RULE_A).simulate(&map, 1000),
+        Settlement::Cycle { length: 2, .. }
+    ));
+
+    assert!(run_incremental_a(&map).is_none());
+    assert_eq!(Day11::part_a(&map), None);
+    assert_eq!(
+        run_until_stable(&map, MAX_GENERATIONS, perform_step_a),
+        None
+    );
+    let bits = BitMap::try_from(&map).unwrap();
+    assert_eq!(bitboard::run_until_stable_a(&bits, MAX_GENERATIONS), None);
+    assert!(Seating::new(&map, RULE_A)
+        .run_incremental(&map, 1)
+        .is_none());
+
+    // The same map settles under the rule of part b.
+    let stable = Seating::new(&map, RULE_B).simulate(&map, 1000);
+    match stable {
+        Settlement::FixedPoint {
+            map: stable,
+            generations,
+        } => {
+            assert_eq!(Day11::part_b(&map), Some(stable.num_occupied()));
+            let incremental = run_incremental_b(&map).unwrap();
+            assert_eq!(incremental.map, stable);
+            assert_eq!(incremental.generations, generations);
+        }
+        other => panic!("part b does not settle: {:?}", other),
+    }
+}
+
+#[test]
+fn maps_are_written_as_they_are_parsed() {
+    let map = random_map(10, 17, 9);
+    assert_eq!(map.to_string().parse::<TileMap>().unwrap(), map);
+
+    let generations: Vec<_> = Seating::new(&map, RULE_A).generations(&map).collect();
+    assert_eq!(generations[0], map);
+    assert_eq!(
+        generations.last().unwrap(),
+        &run_until_stable(&map, MAX_GENERATIONS, perform_step_a).unwrap()
+    );
+}
+
+#[test]
+fn bit_maps_match_tile_maps() {
+    for (seed, width, height) in [
+        (11, 63, 20),
+        (12, 64, 20),
+        (13, 65, 20),
+        (14, 130, 70),
+        (15, 0, 0),
+    ] {
+        let map = random_map(seed, width, height);
+        let bits = BitMap::try_from(&map).unwrap();
+        assert_eq!(bits.to_string(), map.to_string());
+        assert_eq!(bits.to_string().parse::<BitMap>().unwrap(), bits);
+
+        let mut tile_step = TileMap::with_size(map.size());
+        let mut bit_step = BitMap::with_size(bits.size());
+        perform_step_a(&map, &mut tile_step);
+        bitboard::perform_step_a(&bits, &mut bit_step);
+        assert_eq!(TileMap::from(&bit_step), tile_step);
+
+        let stable = bitboard::run_until_stable_a(&bits, MAX_GENERATIONS).unwrap();
+        assert_eq!(
+            TileMap::from(&stable),
+            run_until_stable(&map, MAX_GENERATIONS, perform_step_a).unwrap()
+        );
+        assert_eq!(
+            stable.num_occupied(),
+            run_incremental_a(&map).unwrap().map.num_occupied()
+        );
+    }
+}
+
+/// Everything a seat map can tell about a map, through the interface shared by both kinds of map.
+fn seat_map_summary<M: SeatMap + Debug>(map: &M) -> (Vec<(Option<Tile>, u8, u8)>, String) {
+    let (width, height) = map.size();
+    let mut tiles = Vec::new();
+    for y in 0..height {
+        for x in 0..width {
+            let pos = Vec2::new(x, y);
+            tiles.push((
+                map.get(pos),
+                map.num_adjacent_occupied(pos),
+                map.num_los_occupied(pos),
+            ));
+        }
+    }
+
+    let mut settled = String::new();
+    for &part in &Part::ALL {
+        match map.simulate(part, 1000) {
+            Settlement::FixedPoint { map, generations } => {
+                settled += &format!("{} {} {}\n", part, map.num_occupied(), generations)
+            }
+            other => panic!("{} does not settle: {:?}", part, other),
+        }
+    }
+
+    (tiles, settled)
+}
+
+#[test]
+fn bit_maps_share_the_seat_map_interface() {
+    for (seed, width, height) in [(16, 63, 20), (17, 65, 30), (18, 1, 9), (19, 0, 0)] {
+        let map = random_map(seed, width, height);
+        let bits = BitMap::try_from(&map).unwrap();
+        assert_eq!(seat_map_summary(&bits), seat_map_summary(&map));
+
+        let mut tile_step = map.clone();
+        SeatMap::perform_step_b(&map, &mut tile_step);
+        let mut bit_step = BitMap::with_size(bits.size());
+        bitboard::perform_step_b(&bits, &mut bit_step);
+        assert_eq!(TileMap::from(&bit_step), tile_step);
+    }
+}
+
+#[test]
+fn seats_see_across_wrapped_edges() {
+    let map: TileMap = "LLLLL\nLLLLL\nLLLLL\nLLLLL\nLLLLL".parse().unwrap();
+
+    // On a bounded map the corners have only three neighbours and keep their seats.
+    assert!(matches!(
+        Seating::new(&map, RULE_A).simulate(&map, 100),
+        Settlement::FixedPoint { .. }
+    ));
+
+    // Every seat of a torus has eight neighbours, so all seats are taken and left together.
+    let torus = map.clone().with_topology(Topology::Toroidal);
+    assert_eq!(
+        Seating::new(&torus, RULE_A).simulate(&torus, 100),
+        Settlement::Cycle {
+            map: torus.clone(),
+            start: 0,
+            length: 2
+        }
+    );
+    let mut next = torus.clone();
+    perform_step_a(&torus, &mut next);
+    assert_eq!(next.topology(), Topology::Toroidal);
+
+    // In the corner, three seats are next to each other on a bounded map, five when wrapping
+    // only horizontally and eight on a torus.
+    let corner = Vec2::new(0, 0);
+    let mut full = TileMap::from_str("#####\n#####\n#####\n#####\n#####").unwrap();
+    assert_eq!(full.num_adjacent_occupied(corner), 3);
+    full = full.with_topology(Topology::WrapHorizontal);
+    assert_eq!(full.num_adjacent_occupied(corner), 5);
+    assert_eq!(full.num_los_occupied(corner), 5);
+    full = full.with_topology(Topology::Toroidal);
+    assert_eq!(full.num_adjacent_occupied(corner), 8);
+    assert_eq!(full.num_los_occupied(corner), 8);
+
+    // Bit maps always end at their edges, so they cannot hold maps that wrap.
+    assert_eq!(
+        BitMap::try_from(&full),
+        Err(UnsupportedTopology(Topology::Toroidal))
+    );
+    let wrapping = map.clone().with_topology(Topology::WrapHorizontal);
+    assert_eq!(
+        BitMap::try_from(&wrapping),
+        Err(UnsupportedTopology(Topology::WrapHorizontal))
+    );
+    assert!(BitMap::try_from(&map).is_ok());
+}
+
+#[test]
+fn reports_describe_how_seats_settled() {
+    let map: TileMap = "L.LL.LL.LL\nLLLLLLL.LL\nL.L.L..L..\nLLLL.LL.LL\nL.LL.LL.LL\n\
+        L.LLLLL.LL\n..L.L.....\nLLLLLLLLLL\nL.LLLLLL.L\nL.LLLLL.LL"
+        .parse()
+        .unwrap();
+
+    let report = SeatReport::new(&Seating::new(&map, RULE_A), &map, 100).unwrap();
+    assert_eq!(report.generations(), 5);
+    assert_eq!(report.row_occupancy(), &[5, 3, 2, 6, 2, 6, 0, 6, 1, 6]);
+    assert_eq!(report.column_occupancy().iter().sum::<usize>(), 37);
+    assert_eq!(report.num_never_changed(), 0);
+    assert_eq!(report.most_flipped(), (5, 7));
+    assert_eq!(report.settled()[1], None);
+    assert_eq!(report.settled()[2], Some(3));
+
+    assert_eq!(SeatReport::new(&Seating::new(&map, RULE_A), &map, 4), None);
+}
+
+#[test]
+fn report_tables_line_up_with_wide_cells() {
+    // Twelve seats in a column make the totals two digits wide, so every cell is padded to two.
+    let map: TileMap = "L.L\n".repeat(12).parse().unwrap();
+    let report = SeatReport::new(&Seating::new(&map, RULE_A), &map, 100).unwrap();
+
+    let mut expected = String::from(
+        "stable after 1 generations\n\
+         seats never changed: 0\n\
+         most changes of a seat: 1 (24 seats)\n\n",
+    );
+    expected += &" 1  .  1 | 2\n".repeat(12);
+    expected += "---------\n12  0 12\n";
+    assert_eq!(report.to_string(), expected);
+
+    assert_eq!(
+        format!(
+            "{:>3}|{:<2}|{}",
+            Tile::Floor,
+            Tile::SeatTaken,
+            Tile::SeatEmpty
+        ),
+        "  .|# |L"
+    );
+}
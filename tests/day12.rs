@@ -0,0 +1,243 @@
+use advent2020::day12::{
+    continuous_distance, format_course, parse_continuous, ContinuousCommand, ContinuousShip,
+    CourseCommand, Day12, Ship,
+};
+use advent2020::vector::Vec2;
+use advent2020::Solution;
+
+const EXAMPLE: &str = "F10\nN3\nF7\nR90\nF11\n";
+
+fn assert_close(actual: Vec2<f64>, expected: Vec2<f64>) {
+    assert!(
+        (actual.x - expected.x).abs() < 1e-9 && (actual.y - expected.y).abs() < 1e-9,
+        "{:?} is not {:?}",
+        actual,
+        expected
+    );
+}
+
+#[test]
+fn continuous_ship_follows_cardinal_courses_like_the_ship() {
+    let commands = parse_continuous(EXAMPLE).unwrap();
+    assert_eq!(continuous_distance(&commands), 25);
+    assert_eq!(Day12::part_a(&Day12::parse(EXAMPLE).unwrap()), Some(25));
+
+    let mut ship = Ship::default();
+    for command in Day12::parse(EXAMPLE).unwrap() {
+        ship.set_course(command);
+    }
+    let mut continuous = ContinuousShip::default();
+    for &command in &commands {
+        continuous.set_course(command);
+    }
+    assert_eq!(continuous.pos(), ship.pos());
+    assert_eq!(ContinuousShip::from(&ship).heading_deg(), 180);
+}
+
+#[test]
+fn continuous_ship_turns_by_45_degrees() {
+    let commands = parse_continuous("L45\nF10\nR135\nF10\nE3\n").unwrap();
+    assert_eq!(commands[0], ContinuousCommand::Turn(45));
+    assert_eq!(commands[2], ContinuousCommand::Turn(-135));
+
+    let mut ship = ContinuousShip::default();
+    ship.set_course(commands[0]);
+    assert_eq!(ship.heading_deg(), 315);
+
+    // Heading north east moves the same distance north and east.
+    ship.set_course(commands[1]);
+    let diagonal = 10. / 2f64.sqrt();
+    assert_close(ship.exact_pos(), Vec2::new(-diagonal, -diagonal));
+
+    ship.set_course(commands[2]);
+    assert_eq!(ship.heading_deg(), 180);
+    ship.set_course(commands[3]);
+    ship.set_course(commands[4]);
+    assert_close(ship.exact_pos(), Vec2::new(10. - diagonal, -3. - diagonal));
+    assert_eq!(ship.pos(), Vec2::new(3, -10));
+
+    assert_eq!(continuous_distance(&commands), 13);
+}
+
+#[test]
+fn continuous_turns_wrap_around() {
+    let mut ship = ContinuousShip::default();
+    for command in parse_continuous("R45\nR45\nL720\nR3645\n").unwrap() {
+        ship.set_course(command);
+    }
+    // 270 - 90 - 3645 degrees, where 3645 is ten full turns and another 45 degrees.
+    assert_eq!(ship.heading_deg(), 135);
+}
+
+#[test]
+fn track_exports_as_csv() {
+    let commands = Day12::parse("F10\nN3\nR90\n").unwrap();
+    let track = Ship::default().replay(&commands);
+    assert_eq!(track.points().len(), 4);
+    assert_eq!(
+        track.to_csv(),
+        "step,latitude,longitude,heading\n\
+         0,0,0,E\n\
+         1,0,-10,E\n\
+         2,-3,-10,E\n\
+         3,-3,-10,S\n"
+    );
+}
+
+#[test]
+fn track_exports_as_geojson_with_east_and_north_positive() {
+    let commands = Day12::parse("F10\nN3\nW4\n").unwrap();
+    let track = Ship::default().replay(&commands);
+    assert_eq!(
+        track.to_geojson(),
+        "{\"type\":\"Feature\",\"properties\":{},\"geometry\":{\"type\":\"LineString\",\
+         \"coordinates\":[[0,0],[10,0],[10,3],[6,3]]}}"
+    );
+}
+
+#[test]
+fn waypoint_tracks_record_the_ship() {
+    let commands = Day12::parse(EXAMPLE).unwrap();
+    let track = Ship::default().replay_by_waypoint(&commands);
+    let last = track.points().last().unwrap();
+    assert_eq!(last.pos, Vec2::new(72, -214));
+    assert_eq!(track.to_csv().lines().last(), Some("5,72,-214,E"));
+}
+
+#[test]
+fn course_commands_round_trip_through_text() {
+    use CourseCommand::*;
+
+    let commands = [
+        DeltaLatitude(0),
+        DeltaLatitude(-5),
+        DeltaLatitude(7),
+        DeltaLatitude(i32::MIN),
+        DeltaLatitude(i32::MAX),
+        DeltaLongitude(0),
+        DeltaLongitude(-3),
+        DeltaLongitude(i32::MIN),
+        DeltaLongitude(i32::MAX),
+        Turn(0),
+        Turn(-1),
+        Turn(6),
+        Turn(i32::MIN),
+        Turn(i32::MAX),
+        Forward(0),
+        Forward(u16::MAX),
+    ];
+    for &command in &commands {
+        let text = command.to_string();
+        assert_eq!(text.parse::<CourseCommand>().unwrap(), command, "{}", text);
+    }
+
+    let course = format_course(&commands);
+    assert_eq!(Day12::parse(&course).unwrap(), commands);
+}
+
+#[test]
+fn extreme_course_commands_are_written_as_read() {
+    for text in &[
+        "N2147483648",
+        "S2147483647",
+        "E2147483648",
+        "W2147483647",
+        "R193273528320",
+        "L193273528230",
+        "L270",
+        "R90",
+        "F65535",
+    ] {
+        assert_eq!(text.parse::<CourseCommand>().unwrap().to_string(), *text);
+    }
+
+    assert_eq!(
+        "E2147483648".parse::<CourseCommand>().unwrap(),
+        CourseCommand::DeltaLongitude(i32::MIN)
+    );
+    assert_eq!(
+        "R193273528320".parse::<CourseCommand>().unwrap(),
+        CourseCommand::Turn(i32::MIN)
+    );
+    assert!("N2147483649".parse::<CourseCommand>().is_err());
+    assert!("L193273528320".parse::<CourseCommand>().is_err());
+    assert!("F65536".parse::<CourseCommand>().is_err());
+}
+
+#[test]
+fn normalized_turns_are_the_shortest_equivalent() {
+    use CourseCommand::*;
+
+    let cases = [
+        (Turn(0), Turn(0)),
+        (Turn(1), Turn(1)),
+        (Turn(2), Turn(2)),
+        (Turn(-2), Turn(2)),
+        (Turn(3), Turn(-1)),
+        (Turn(-1), Turn(-1)),
+        (Turn(-3), Turn(1)),
+        (Turn(4), Turn(0)),
+        (Turn(i32::MIN), Turn(0)),
+        (Turn(i32::MAX), Turn(-1)),
+        (Forward(7), Forward(7)),
+        (DeltaLatitude(i32::MIN), DeltaLatitude(i32::MIN)),
+    ];
+    for &(command, normalized) in &cases {
+        assert_eq!(command.normalized(), normalized, "{:?}", command);
+        assert_eq!(
+            normalized.to_string().parse::<CourseCommand>().unwrap(),
+            normalized
+        );
+    }
+
+    // Normalizing does not change where the ship ends up.
+    let course = Day12::parse("L270\nF10\nR540\nF3\nL360\nN4\n").unwrap();
+    let normalized: Vec<CourseCommand> =
+        course.iter().map(|command| command.normalized()).collect();
+    assert_eq!(format_course(&normalized), "R90\nF10\nL180\nF3\nL0\nN4");
+    assert_eq!(Day12::part_a(&course), Day12::part_a(&normalized));
+    assert_eq!(Day12::part_b(&course), Day12::part_b(&normalized));
+}
+
+#[test]
+fn waypoint_courses_leaving_the_range_have_no_solution() {
+    let commands = Day12::parse("N40000\nF65535\n").unwrap();
+    assert_eq!(Day12::part_b(&commands), None);
+
+    let mut ship = Ship::default();
+    assert_eq!(ship.checked_set_course_by_waypoint(commands[0]), Some(()));
+    assert_eq!(ship.checked_set_course_by_waypoint(commands[1]), None);
+    assert_eq!(ship.pos(), Vec2::new(0, 0));
+}
+
+#[test]
+fn extreme_turns_are_reduced_before_turning() {
+    let commands = Day12::parse("R193273528320\nF1\n").unwrap();
+    assert_eq!(commands[0], CourseCommand::Turn(i32::MIN));
+
+    let mut ship = Ship::default();
+    ship.set_course(commands[0]);
+    assert_eq!(ship.heading(), Ship::default().heading());
+
+    let mut ship = Ship::default();
+    ship.set_course_by_waypoint(commands[0]);
+    assert_eq!(ship.waypoint(), Ship::default().waypoint());
+
+    assert_eq!(Day12::part_a(&commands), Some(1));
+    assert_eq!(Day12::part_b(&commands), Some(11));
+}
+
+#[test]
+fn courses_leaving_the_range_have_no_solution() {
+    let commands = Day12::parse("N2147483648\nN1\n").unwrap();
+    assert_eq!(commands[0], CourseCommand::DeltaLatitude(i32::MIN));
+    assert_eq!(Day12::part_a(&commands), None);
+
+    let mut ship = Ship::default();
+    assert_eq!(ship.checked_set_course(commands[0]), Some(()));
+    assert_eq!(ship.checked_set_course(commands[1]), None);
+    assert_eq!(ship.pos(), Vec2::new(i32::MIN, 0));
+
+    // The distance to the start does not fit into an `i32` either.
+    assert_eq!(Day12::part_a(&commands[..1].to_vec()), None);
+}
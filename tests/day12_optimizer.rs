@@ -0,0 +1,94 @@
+use advent2020::day12::optimizer::optimize_course;
+use advent2020::day12::{CourseCommand, Heading, Ship};
+
+#[path = "../benches/generate/mod.rs"]
+#[allow(dead_code)]
+mod generate;
+
+use generate::Rng;
+
+/// A number in `0..bound`, to build commands from.
+fn below(rng: &mut Rng, bound: u64) -> i32 {
+    rng.below(bound) as i32
+}
+
+fn random_command(rng: &mut Rng) -> CourseCommand {
+    match below(rng, 4) {
+        0 => CourseCommand::DeltaLatitude(below(rng, 201) - 100),
+        1 => CourseCommand::DeltaLongitude(below(rng, 201) - 100),
+        2 => CourseCommand::Turn(below(rng, 9) - 4),
+        _ => CourseCommand::Forward(below(rng, 101) as u16),
+    }
+}
+
+fn parse_course(input: &str) -> Vec<CourseCommand> {
+    input.lines().map(|line| line.parse().unwrap()).collect()
+}
+
+fn follow(commands: &[CourseCommand]) -> Ship {
+    let mut ship = Ship::default();
+    for &command in commands {
+        ship.set_course(command);
+    }
+    ship
+}
+
+#[test]
+fn optimized_courses_end_at_the_same_position() {
+    let mut rng = Rng::new(0x2020_1212);
+
+    for _ in 0..1000 {
+        let len = below(&mut rng, 40) as usize;
+        let course: Vec<_> = (0..len).map(|_| random_command(&mut rng)).collect();
+        let expected = follow(&course);
+
+        for heading in [None, Some(Ship::default().heading())] {
+            let optimized = optimize_course(&course, heading);
+            let ship = follow(&optimized);
+
+            assert_eq!(
+                ship.pos(),
+                expected.pos(),
+                "{:?} -> {:?}",
+                course,
+                optimized
+            );
+            assert_eq!(
+                ship.heading(),
+                expected.heading(),
+                "{:?} -> {:?}",
+                course,
+                optimized
+            );
+            assert!(optimized.len() <= course.len().max(1));
+        }
+    }
+}
+
+#[test]
+fn redundant_commands_are_merged() {
+    let course = parse_course("N3\nN5\nS2\nL90\nR90\nF10\nF5");
+    assert_eq!(
+        optimize_course(&course, None),
+        vec![CourseCommand::DeltaLatitude(-6), CourseCommand::Forward(15)]
+    );
+    assert_eq!(
+        optimize_course(&course, Some(Heading::East)),
+        vec![
+            CourseCommand::DeltaLatitude(-6),
+            CourseCommand::DeltaLongitude(-15)
+        ]
+    );
+}
+
+#[test]
+fn extreme_turns_are_optimized() {
+    let course = vec![CourseCommand::Turn(i32::MIN), CourseCommand::Forward(1)];
+    let expected = follow(&course);
+
+    for heading in [None, Some(Heading::East)] {
+        let ship = follow(&optimize_course(&course, heading));
+        assert_eq!(ship.pos(), expected.pos());
+        assert_eq!(ship.heading(), expected.heading());
+    }
+}
@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+
+use advent2020::day12::planner::plan_course;
+use advent2020::day12::{CourseCommand, Ship};
+use advent2020::vector::Vec2;
+
+fn follow(start: &Ship, commands: &[CourseCommand]) -> Ship {
+    let mut ship = start.clone();
+    for &command in commands {
+        ship.set_course(command);
+    }
+    ship
+}
+
+/// The length of the shortest course to every position reachable in at most `max_len` commands,
+/// found by trying every possible course.
+fn brute_force(alphabet: &[CourseCommand], max_len: usize) -> HashMap<Vec2<i32>, usize> {
+    let mut shortest = HashMap::new();
+    let mut courses = vec![Vec::new()];
+    for len in 0..=max_len {
+        let mut longer = Vec::new();
+        for course in courses {
+            let pos = follow(&Ship::default(), &course).pos();
+            shortest.entry(pos).or_insert(len);
+            for &command in alphabet {
+                let mut next = course.clone();
+                next.push(command);
+                longer.push(next);
+            }
+        }
+        courses = longer;
+    }
+
+    shortest
+}
+
+#[test]
+fn planned_courses_are_as_short_as_brute_force() {
+    use CourseCommand::*;
+
+    const MAX_LEN: usize = 6;
+    let alphabets = [
+        vec![Forward(3), Turn(1), DeltaLatitude(2), DeltaLongitude(-1)],
+        vec![Forward(1), Turn(-1), Turn(2)],
+        vec![DeltaLatitude(5), DeltaLongitude(3), DeltaLongitude(-2)],
+    ];
+
+    for alphabet in &alphabets {
+        let shortest = brute_force(alphabet, MAX_LEN);
+        for x in -20..=20 {
+            for y in -20..=20 {
+                let target = Vec2::new(x, y);
+                let plan = plan_course(&Ship::default(), target, alphabet, MAX_LEN);
+                match shortest.get(&target) {
+                    Some(&len) => {
+                        let plan = plan.unwrap_or_else(|| {
+                            panic!("no course to {:?} with {:?}", target, alphabet)
+                        });
+                        assert_eq!(plan.len(), len, "course {:?} to {:?}", plan, target);
+                        assert_eq!(follow(&Ship::default(), &plan).pos(), target);
+                        assert!(plan.iter().all(|command| alphabet.contains(command)));
+                    }
+                    None => assert_eq!(plan, None, "{:?} is too far for {:?}", target, alphabet),
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn courses_longer_than_the_limit_are_not_found() {
+    let alphabet = [CourseCommand::Forward(10), CourseCommand::Turn(1)];
+    let target = Vec2::new(0, -30);
+    assert_eq!(plan_course(&Ship::default(), target, &alphabet, 2), None);
+    assert_eq!(
+        plan_course(&Ship::default(), target, &alphabet, 3),
+        Some(vec![CourseCommand::Forward(10); 3])
+    );
+
+    // Without any command that moves the ship, only the start can be reached.
+    let turns = [CourseCommand::Turn(1)];
+    assert_eq!(plan_course(&Ship::default(), target, &turns, 100), None);
+    assert_eq!(
+        plan_course(&Ship::default(), Vec2::new(0, 0), &turns, 0),
+        Some(Vec::new())
+    );
+}
+
+#[test]
+fn extreme_commands_do_not_overflow() {
+    let alphabet = [
+        CourseCommand::DeltaLatitude(i32::MIN),
+        CourseCommand::DeltaLongitude(i32::MIN),
+        CourseCommand::Forward(u16::MAX),
+    ];
+    let target = Vec2::new(i32::MIN, i32::MIN);
+    let plan = plan_course(&Ship::default(), target, &alphabet, 4).unwrap();
+    assert_eq!(plan.len(), 2);
+    assert_eq!(follow(&Ship::default(), &plan).pos(), target);
+
+    let far = Vec2::new(i32::MAX, i32::MAX);
+    assert_eq!(plan_course(&Ship::default(), far, &alphabet, 1), None);
+}